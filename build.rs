@@ -66,6 +66,19 @@ mod tests {
             panic!("{}", std::str::from_utf8(&stderr).unwrap());
         }
     }
+    pub fn compile_dependent_test_lib() {
+        let output = Command::new("mcs")
+            .arg("-target:library")
+            .arg("-r:test/dlls/Test.dll")
+            .arg("-out:test/dlls/Dependent.dll")
+            .arg("test/Dependent.cs")
+            .output()
+            .expect("Failed to execute command");
+        let stderr = output.stderr;
+        if stderr.len() > 0 {
+            panic!("{}", std::str::from_utf8(&stderr).unwrap());
+        }
+    }
 }
 fn is_docs() -> bool {
     std::env::var("DOCS_RS").is_ok()
@@ -117,6 +130,7 @@ fn main() {
         tests::compile_pinvoke_test_assembly();
         tests::compile_jit_test_assembly();
         tests::compile_test_lib();
+        tests::compile_dependent_test_lib();
     }
 }
 #[cfg(target_os = "linux")]
@@ -11,6 +11,8 @@ use std::ops::Index;
 /// # Nullable support
 /// [`Array<Dim, T>`] is non-nullable on default and will panic when null passed as argument form managed code.
 /// For nullable support use [`Option<Array<Dim, T>>`].
+/// # Reference types
+/// Elements can also be references to managed objects(e.g. `Array<Dim1D, MString>` for `string[]`). Such elements are read as object pointers and wrapped, and stored with a write barrier.
 /*
     why is there a weird constraint "where [();DIMENSIONS as usize]:Copy" in array type? It guarantees that Dimensions is higher than 0 and size array is larger than 0,
     so Array<DIMENSIONS,T> can exist.
@@ -36,11 +38,12 @@ where
     <Dim as DimensionTrait>::Lengths: BorrowMut<[usize]>,
 {
     // Private function used to calculate index in an array based on its dimensions.
+    // Managed arrays are row-major, so the last index changes fastest.
     fn get_index(&self, indices: Dim::Lengths) -> usize {
         //size of current dimension
         let mut size = 1;
         let mut index = 0;
-        for (n, ind) in indices.borrow().iter().enumerate() {
+        for (n, ind) in indices.borrow().iter().enumerate().rev() {
             let len = self.lengths[n];
             #[cfg(not(feature = "unsafe_arrays"))]
             assert!(
@@ -54,7 +57,24 @@ where
         }
         index
     }
+    // Private function used to check if *indices* are within bounds of this array.
+    fn check_index(&self, indices: &Dim::Lengths) -> Result<(), ArrayIndexError> {
+        let lengths: &[usize] = self.lengths.borrow();
+        for (dimension, (index, length)) in indices.borrow().iter().zip(lengths).enumerate() {
+            if index >= length {
+                return Err(ArrayIndexError {
+                    dimension,
+                    index: *index,
+                    length: *length,
+                });
+            }
+        }
+        Ok(())
+    }
     /// Function returning element at *index*
+    /// # Panics
+    /// Panics if *indices* are outside of the array bounds. If `unsafe_arrays` feature is enabled this check is skipped, and reading outside of bounds is **undefined behaviour**.
+    /// Use [`Self::try_get`] to handle out of bounds access gracefully.
     /// # Arguments
     /// |Name   |Type   |Description|
     /// |-------|-------|------|
@@ -97,7 +117,37 @@ where
         rr
     }
 
+    /// Function returning element at *index*, or an [`ArrayIndexError`] if any of *indices* is outside of the array bounds.
+    /// The bounds are always checked, even if `unsafe_arrays` feature is enabled.
+    /// # Errors
+    /// Returns [`ArrayIndexError`] describing the first index which was out of range.
+    /// # Example
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// fn get_or_zero(input:&Array<Dim1D,f32>,index:usize)->f32{
+    ///     input.try_get([index]).unwrap_or(0.0)
+    /// }
+    /// ```
+    pub fn try_get(&self, indices: Dim::Lengths) -> Result<T, ArrayIndexError> {
+        self.check_index(&indices)?;
+        Ok(self.get(indices))
+    }
+    /// Function setting element at *index* of [`Array`] to *value*, or returning an [`ArrayIndexError`] if any of *indices* is outside of the array bounds.
+    /// The bounds are always checked, even if `unsafe_arrays` feature is enabled.
+    /// # Errors
+    /// Returns [`ArrayIndexError`] describing the first index which was out of range. The array is left unchanged.
+    pub fn try_set(&mut self, indices: Dim::Lengths, value: T) -> Result<(), ArrayIndexError>
+    where
+        T: InteropSend,
+    {
+        self.check_index(&indices)?;
+        self.set(indices, value);
+        Ok(())
+    }
     /// Function setting element at *index* of [`Array`] to *value*
+    /// # Panics
+    /// Panics if *indices* are outside of the array bounds. If `unsafe_arrays` feature is enabled this check is skipped, and writing outside of bounds is **undefined behaviour**.
+    /// Use [`Self::try_set`] to handle out of bounds access gracefully.
     /// # Arguments
     /// |Name   |Type   |Description|
     /// |-------|-------|------|
@@ -132,21 +182,111 @@ where
             #[allow(clippy::cast_possible_wrap)]
             crate::binds::mono_array_addr_with_size(
                 self.get_ptr().cast(),
-                std::mem::size_of::<T>() as i32,
+                std::mem::size_of::<T::SourceType>() as i32,
                 index,
             )
         };
         if T::is_class_type() {
-            unsafe { (*ptr.cast()) = value.get_ffi_ptr() };
+            // References must be stored with a write barrier, so the garbage collector knows about them.
+            unsafe {
+                crate::binds::mono_gc_wbarrier_set_arrayref(
+                    self.get_ptr().cast(),
+                    ptr.cast(),
+                    value.get_ffi_ptr().cast(),
+                );
+            };
         } else {
-            unsafe { (*ptr.cast()) = value };
+            unsafe { value.write_to_mono(ptr.cast()) };
         }
 
         #[cfg(feature = "referenced_objects")]
         gc_unsafe_exit(marker);
     }
+    /// Copies all elements of *src* into this array in one go, which is much faster than setting elements one by one. For multi-dimensional arrays elements are copied in row-major order.
+    /// Only available for types which are passed to managed code unchanged(e.g. numbers, or structs deriving [`crate::MonoBlittable`]).
+    /// # Panics
+    /// Panics if length of *src* is different from the total element count of this array.
+    /// # Example
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// fn fill(samples:&mut Array<Dim1D,f32>,data:&[f32]){
+    ///     samples.copy_from_slice(data);
+    /// }
+    /// ```
+    pub fn copy_from_slice(&mut self, src: &[T])
+    where
+        T: InteropReceive<SourceType = T> + Copy,
+    {
+        let len = self.len();
+        assert_eq!(
+            len,
+            src.len(),
+            "Can't copy slice of length {} into array of length {len}!",
+            src.len()
+        );
+        if len == 0 {
+            return;
+        }
+        #[cfg(feature = "referenced_objects")]
+        let marker = gc_unsafe_enter();
+        #[allow(clippy::cast_possible_truncation)]
+        #[allow(clippy::cast_possible_wrap)]
+        unsafe {
+            let first = crate::binds::mono_array_addr_with_size(
+                self.get_ptr().cast(),
+                std::mem::size_of::<T>() as i32,
+                0,
+            )
+            .cast::<T>();
+            std::ptr::copy_nonoverlapping(src.as_ptr(), first, len);
+        }
+        #[cfg(feature = "referenced_objects")]
+        gc_unsafe_exit(marker);
+    }
+    /// Copies first `dst.len()` elements of this array into *dst* in one go, which is much faster than reading elements one by one. For multi-dimensional arrays elements are copied in row-major order.
+    /// Only available for types which are passed to managed code unchanged(e.g. numbers, or structs deriving [`crate::MonoBlittable`]).
+    /// # Panics
+    /// Panics if *dst* is longer than the total element count of this array.
+    /// # Example
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// fn read(samples:&Array<Dim1D,f32>)->Vec<f32>{
+    ///     let mut data = vec![0.0;samples.len()];
+    ///     samples.copy_to_slice(&mut data);
+    ///     data
+    /// }
+    /// ```
+    pub fn copy_to_slice(&self, dst: &mut [T])
+    where
+        T: InteropReceive<SourceType = T> + Copy,
+    {
+        let len = self.len();
+        assert!(
+            dst.len() <= len,
+            "Can't copy {} elements out of array of length {len}!",
+            dst.len()
+        );
+        if dst.is_empty() {
+            return;
+        }
+        #[cfg(feature = "referenced_objects")]
+        let marker = gc_unsafe_enter();
+        #[allow(clippy::cast_possible_truncation)]
+        #[allow(clippy::cast_possible_wrap)]
+        unsafe {
+            let first = crate::binds::mono_array_addr_with_size(
+                self.get_ptr().cast(),
+                std::mem::size_of::<T>() as i32,
+                0,
+            )
+            .cast::<T>();
+            std::ptr::copy_nonoverlapping(first, dst.as_mut_ptr(), dst.len());
+        }
+        #[cfg(feature = "referenced_objects")]
+        gc_unsafe_exit(marker);
+    }
 
-    /// Function returning 1D length of the array(element count).
+    /// Function returning total element count of the array. For multi-dimensional arrays it is a product of lengths of all dimensions.
     /// # Arguments
     /// |Name   |Type   |Description|
     /// |-------|-------|------|
@@ -259,6 +399,41 @@ where
             Dim::DIMENSIONS as u32,
         )
     }
+    /// Returns length of *dimension* of this array.
+    /// # Arguments
+    /// |Name   |Type   |Description|
+    /// |-------|-------|------|
+    /// |self|&Array|Array to get length of|
+    /// |dimension|usize|Index of dimension to get length of|
+    /// # Panics
+    /// Panics if *dimension* is not lower than count of dimensions of this array.
+    #[must_use]
+    pub fn length(&self, dimension: usize) -> usize {
+        let lengths: &[usize] = self.lengths.borrow();
+        assert!(
+            dimension < lengths.len(),
+            "dimension ({dimension}) outside of array rank ({})",
+            lengths.len()
+        );
+        lengths[dimension]
+    }
+    /// Returns size of a single element of this array, in bytes.
+    #[must_use]
+    pub fn element_size() -> usize {
+        #[allow(clippy::cast_sign_loss)]
+        let size =
+            unsafe { crate::binds::mono_array_element_size(Self::get_class().get_ptr()) } as usize;
+        size
+    }
+    /// Returns size of data stored in this array, in bytes(`len * element_size`). Unlike [`ObjectTrait::get_size`], it does not include the size of object header and array bounds.
+    /// # Arguments
+    /// |Name   |Type   |Description|
+    /// |-------|-------|------|
+    /// |self|&Array|Array to get size of|
+    #[must_use]
+    pub fn get_size(&self) -> usize {
+        self.len() * Self::element_size()
+    }
     /// Returns n-dimensional length of this array.
     /// # Arguments
     /// |Name   |Type   |Description|
@@ -267,6 +442,16 @@ where
     pub fn get_lengths(&self) -> Dim::Lengths {
         self.lengths
     }
+    /// Returns class of elements of the managed array(e.g. `System.Int32` for `int[]`). Can be used to check that the array matches the expected element type *T*.
+    #[must_use]
+    pub fn element_class(&self) -> Class {
+        ObjectTrait::get_class(self).get_element_class()
+    }
+    /// Returns number of dimensions of the managed array.
+    #[must_use]
+    pub fn rank(&self) -> i32 {
+        ObjectTrait::get_class(self).get_rank()
+    }
 }
 impl<Dim: DimensionTrait, T: InteropSend + InteropReceive + InteropClass> InteropClass
     for Array<Dim, T>
@@ -365,8 +550,108 @@ where
         self.get_ptr() == other.get_ptr().cast()
     }
 }
+/// Error returned when trying to access an element outside of bounds of an [`Array`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArrayIndexError {
+    /// Dimension in which the index was out of range.
+    pub dimension: usize,
+    /// The index which was out of range.
+    pub index: usize,
+    /// Length of the array in that dimension.
+    pub length: usize,
+}
+impl std::fmt::Display for ArrayIndexError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "index ({}) outside of array bound ({}) in dimension {}",
+            self.index, self.length, self.dimension
+        )
+    }
+}
+impl std::error::Error for ArrayIndexError {}
 use crate::dimensions::Dim1D;
 
+impl<T: InteropSend + InteropReceive + InteropClass> Array<Dim1D, T> {
+    /// Copies all elements of this array into a [`Vec`]. Elements are copied out of the managed array in one go, and only then converted to rust representation.
+    /// # Example
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// fn sum(input:&Array<Dim1D,i32>)->i32{
+    ///     input.to_vec().iter().sum()
+    /// }
+    /// ```
+    #[must_use]
+    pub fn to_vec(&self) -> Vec<T> {
+        let len = self.len();
+        if len == 0 {
+            return Vec::new();
+        }
+        #[cfg(feature = "referenced_objects")]
+        let marker = gc_unsafe_enter();
+        #[allow(clippy::cast_possible_truncation)]
+        #[allow(clippy::cast_possible_wrap)]
+        let src: Vec<T::SourceType> = unsafe {
+            let first = crate::binds::mono_array_addr_with_size(
+                self.get_ptr().cast(),
+                std::mem::size_of::<T::SourceType>() as i32,
+                0,
+            ) as *const T::SourceType;
+            std::slice::from_raw_parts(first, len).to_vec()
+        };
+        // Class types must be converted before leaving the gc unsafe region, since a collection could move them.
+        let res = src.into_iter().map(T::get_rust_rep).collect();
+        #[cfg(feature = "referenced_objects")]
+        gc_unsafe_exit(marker);
+        res
+    }
+    /// Returns an iterator over elements of this array.
+    #[must_use]
+    pub fn iter(&self) -> ArrayIter<T> {
+        self.clone().into_iter()
+    }
+}
+/// Iterator over elements of a one dimensional [`Array`], created by [`Array::iter`] or [`IntoIterator::into_iter`].
+pub struct ArrayIter<T: InteropSend + InteropReceive + InteropClass> {
+    array: Array<Dim1D, T>,
+    index: usize,
+    len: usize,
+}
+impl<T: InteropSend + InteropReceive + InteropClass> Iterator for ArrayIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        if self.index >= self.len {
+            return None;
+        }
+        let res = self.array.get([self.index]);
+        self.index += 1;
+        Some(res)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+impl<T: InteropSend + InteropReceive + InteropClass> ExactSizeIterator for ArrayIter<T> {}
+impl<T: InteropSend + InteropReceive + InteropClass> IntoIterator for Array<Dim1D, T> {
+    type Item = T;
+    type IntoIter = ArrayIter<T>;
+    fn into_iter(self) -> ArrayIter<T> {
+        let len = self.len();
+        ArrayIter {
+            array: self,
+            index: 0,
+            len,
+        }
+    }
+}
+impl<T: InteropSend + InteropReceive + InteropClass> IntoIterator for &Array<Dim1D, T> {
+    type Item = T;
+    type IntoIter = ArrayIter<T>;
+    fn into_iter(self) -> ArrayIter<T> {
+        self.iter()
+    }
+}
 impl<T: InteropSend + InteropReceive + InteropClass + Clone> From<&[T]> for Array<Dim1D, T> {
     fn from(src: &[T]) -> Self {
         let size = src.len();
@@ -1,6 +1,9 @@
-use crate::binds::MonoAssembly;
+use crate::binds::{MonoAssembly, MonoAssemblyName};
 use crate::image::Image;
-use std::ffi::CString;
+use crate::metadata::{MetadataTableInfo, MetadataTableKind};
+use crate::tupleutilis::{CompareClasses, TupleToFFIPtrs};
+use crate::Method;
+use std::ffi::{CStr, CString};
 /// Safe representation of an executable file containing managed code and data about it.
 #[derive(Clone, Copy)]
 pub struct Assembly {
@@ -51,6 +54,62 @@ impl Assembly {
         let _ = cstr_name.into_raw(); //release pointer
         name
     }
+    /// Opens assembly at *path* and loads it into the current domain.
+    /// Unlike [`crate::Domain::assembly_open`], reports why loading failed. Assemblies it references are loaded too, so missing dependencies are reported
+    /// when it is opened, instead of when code using them first runs.
+    /// # Example
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// # let domain = jit::init("name",None);
+    /// match Assembly::open("SomeAssembly.dll"){
+    ///     Ok(asm)=>println!("Loaded {}",asm.get_name()),
+    ///     Err(err)=>eprintln!("Could not load SomeAssembly.dll:{err}"),
+    /// }
+    /// ```
+    /// # Errors
+    /// Returns [`AssemblyLoadError`] matching the status reported by the runtime.
+    pub fn open(path: &str) -> Result<Self, AssemblyLoadError> {
+        let cstr = CString::new(path).expect(crate::STR2CSTR_ERR);
+        let mut status = crate::binds::MonoImageOpenStatus_MONO_IMAGE_OK;
+        let ptr = unsafe {
+            crate::binds::mono_assembly_open(cstr.as_ptr(), std::ptr::addr_of_mut!(status))
+        };
+        drop(cstr);
+        if ptr.is_null() {
+            return Err(AssemblyLoadError::from_status(status));
+        }
+        let asm = unsafe { Self::from_ptr(ptr) };
+        let dir = std::path::Path::new(path)
+            .parent()
+            .and_then(|dir| dir.to_str());
+        asm.load_references(dir.filter(|dir| !dir.is_empty()).unwrap_or("."))?;
+        Ok(asm)
+    }
+    // Loads assemblies referenced by this assembly, looking for them in *basedir* first. The runtime loads references only when they are first used,
+    // so without this a missing reference would not be reported.
+    fn load_references(&self, basedir: &str) -> Result<(), AssemblyLoadError> {
+        let basedir = CString::new(basedir).expect(crate::STR2CSTR_ERR);
+        for reference in self.get_references() {
+            let name = CString::new(reference.get_name()).expect(crate::STR2CSTR_ERR);
+            let mut status = crate::binds::MonoImageOpenStatus_MONO_IMAGE_OK;
+            let assembly_name = unsafe { crate::binds::mono_assembly_name_new(name.as_ptr()) };
+            if assembly_name.is_null() {
+                return Err(AssemblyLoadError::MissingAssemblyRef);
+            }
+            let loaded = unsafe {
+                crate::binds::mono_assembly_load(
+                    assembly_name,
+                    basedir.as_ptr(),
+                    std::ptr::addr_of_mut!(status),
+                )
+            };
+            unsafe { crate::binds::mono_assembly_name_free(assembly_name) };
+            if loaded.is_null() {
+                return Err(AssemblyLoadError::MissingAssemblyRef);
+            }
+        }
+        Ok(())
+    }
     /// Checks if assembly *name* is loaded, and if it is returns that assembly.
     #[must_use]
     pub fn assembly_loaded(name: &str) -> Option<Self> {
@@ -65,8 +124,174 @@ impl Assembly {
             unsafe { Some(Self::from_ptr(ptr)) }
         }
     }
+    /// Returns entry point(`Main` method) of this assembly, or [`None`] if it has no entry point(e.g. it is a library) or *Args* do not match its parameters.
+    /// Shorthand for [`Image::get_entry_point`] called on the image of this assembly.
+    /// # Example
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// # let domain = jit::init("name",None);
+    /// let asm = domain.assembly_open("SomeProgram.exe").expect("Could not load assembly!");
+    /// let main:Method<(Array<Dim1D,String>,)> = asm.get_entry_point().expect("Assembly has no entry point!");
+    /// ```
+    #[must_use]
+    pub fn get_entry_point<Args: TupleToFFIPtrs + CompareClasses>(&self) -> Option<Method<Args>> {
+        self.get_image().get_entry_point()
+    }
+    /// Returns names of assemblies referenced by this assembly. Referenced assemblies are not loaded.
+    /// # Example
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// # let domain = jit::init("name",None);
+    /// # let asm = domain.assembly_open("SomeAssembly.dll").unwrap();
+    /// for reference in asm.get_references(){
+    ///     println!("SomeAssembly depends on {reference}");
+    /// }
+    /// ```
+    #[must_use]
+    pub fn get_references(&self) -> Vec<AssemblyName> {
+        let img = self.get_image();
+        let table = img.get_table_info(MetadataTableKind::AssemblyRef);
+        (0..table.get_table_rows())
+            .map(|row| AssemblyName::from_row(&table, img, row))
+            .collect()
+    }
     /// Releases reference to assembly. Assembly is closed when all outside references  to it are released.
     pub fn close(self) {
         unsafe { crate::binds::mono_assembly_close(self.ptr) };
+        crate::class::invalidate_class_caches();
+    }
+}
+/// Name and version of an assembly, as stored in a reference to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssemblyName {
+    name: String,
+    culture: String,
+    major_version: u32,
+    minor_version: u32,
+    build_number: u32,
+    rev_number: u32,
+}
+impl AssemblyName {
+    // Decodes row *row* of the `AssemblyRef` metadata table.
+    fn from_row(table: &MetadataTableInfo, img: Image, row: i32) -> Self {
+        use crate::binds::{
+            MONO_ASSEMBLYREF_BUILD_NUMBER, MONO_ASSEMBLYREF_CULTURE,
+            MONO_ASSEMBLYREF_MAJOR_VERSION, MONO_ASSEMBLYREF_MINOR_VERSION, MONO_ASSEMBLYREF_NAME,
+            MONO_ASSEMBLYREF_REV_NUMBER,
+        };
+        Self {
+            name: img.metadata_string_heap(table.decode_row_col(row, MONO_ASSEMBLYREF_NAME)),
+            culture: img.metadata_string_heap(table.decode_row_col(row, MONO_ASSEMBLYREF_CULTURE)),
+            major_version: table.decode_row_col(row, MONO_ASSEMBLYREF_MAJOR_VERSION),
+            minor_version: table.decode_row_col(row, MONO_ASSEMBLYREF_MINOR_VERSION),
+            build_number: table.decode_row_col(row, MONO_ASSEMBLYREF_BUILD_NUMBER),
+            rev_number: table.decode_row_col(row, MONO_ASSEMBLYREF_REV_NUMBER),
+        }
+    }
+    /// Creates [`AssemblyName`] from a [`MonoAssemblyName`] pointer.
+    /// # Safety
+    /// *ptr* must be a valid [`MonoAssemblyName`] pointer.
+    #[must_use]
+    pub unsafe fn from_name_ptr(ptr: *mut MonoAssemblyName) -> Self {
+        let to_string = |cstr: *const std::ffi::c_char| {
+            if cstr.is_null() {
+                String::new()
+            } else {
+                CStr::from_ptr(cstr)
+                    .to_str()
+                    .expect(crate::CSTR2STR_ERR)
+                    .to_owned()
+            }
+        };
+        let (mut minor, mut build, mut revision) = (0, 0, 0);
+        let major = crate::binds::mono_assembly_name_get_version(
+            ptr,
+            std::ptr::addr_of_mut!(minor),
+            std::ptr::addr_of_mut!(build),
+            std::ptr::addr_of_mut!(revision),
+        );
+        Self {
+            name: to_string(crate::binds::mono_assembly_name_get_name(ptr)),
+            culture: to_string(crate::binds::mono_assembly_name_get_culture(ptr)),
+            major_version: major.into(),
+            minor_version: minor.into(),
+            build_number: build.into(),
+            rev_number: revision.into(),
+        }
+    }
+    /// Returns simple name of the assembly(e.g. `mscorlib`).
+    #[must_use]
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+    /// Returns culture of the assembly. Empty for culture-neutral assemblies.
+    #[must_use]
+    pub fn get_culture(&self) -> String {
+        self.culture.clone()
+    }
+    /// Returns major version of the assembly.
+    #[must_use]
+    pub fn get_major_version(&self) -> u32 {
+        self.major_version
+    }
+    /// Returns minor version of the assembly.
+    #[must_use]
+    pub fn get_minor_version(&self) -> u32 {
+        self.minor_version
+    }
+    /// Returns build number of the assembly.
+    #[must_use]
+    pub fn get_build_number(&self) -> u32 {
+        self.build_number
+    }
+    /// Returns revision number of the assembly.
+    #[must_use]
+    pub fn get_rev_number(&self) -> u32 {
+        self.rev_number
+    }
+}
+/// Formats name as `Name, Version=Major.Minor.Build.Revision`.
+impl std::fmt::Display for AssemblyName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}, Version={}.{}.{}.{}",
+            self.name, self.major_version, self.minor_version, self.build_number, self.rev_number
+        )
+    }
+}
+/// Reason why an assembly could not be loaded. Mirrors `MonoImageOpenStatus`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssemblyLoadError {
+    /// The file could not be read(e.g. it is missing, or there are not enough permissions to read it).
+    ErrorErrno,
+    /// One of the assemblies referenced by this assembly could not be found.
+    MissingAssemblyRef,
+    /// The file is not a valid managed assembly(bad image format).
+    ImageInvalid,
+}
+impl AssemblyLoadError {
+    /// Converts status reported by the runtime to [`AssemblyLoadError`]. Unknown statuses are treated as [`AssemblyLoadError::ImageInvalid`].
+    /// Status `MONO_IMAGE_OK` means the runtime failed without reporting why, so it is treated as [`AssemblyLoadError::ErrorErrno`].
+    #[must_use]
+    pub fn from_status(status: crate::binds::MonoImageOpenStatus) -> Self {
+        match status {
+            crate::binds::MonoImageOpenStatus_MONO_IMAGE_OK
+            | crate::binds::MonoImageOpenStatus_MONO_IMAGE_ERROR_ERRNO => Self::ErrorErrno,
+            crate::binds::MonoImageOpenStatus_MONO_IMAGE_MISSING_ASSEMBLYREF => {
+                Self::MissingAssemblyRef
+            }
+            _ => Self::ImageInvalid,
+        }
+    }
+}
+impl std::fmt::Display for AssemblyLoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ErrorErrno => write!(f, "could not read the assembly file"),
+            Self::MissingAssemblyRef => write!(f, "a referenced assembly is missing"),
+            Self::ImageInvalid => write!(f, "file is not a valid assembly image"),
+        }
     }
 }
+impl std::error::Error for AssemblyLoadError {}
@@ -11355,6 +11355,42 @@ extern "C" {
 extern "C" {
     pub fn mono_is_debugger_attached() -> mono_bool;
 }
+extern "C" {
+    pub fn mono_thread_attach(domain: *mut MonoDomain) -> *mut MonoThread;
+}
+extern "C" {
+    pub fn mono_thread_detach(thread: *mut MonoThread);
+}
+pub type MonoPrintCallback = ::std::option::Option<
+    unsafe extern "C" fn(string: *const ::std::os::raw::c_char, is_stdout: mono_bool),
+>;
+pub type MonoLogCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        log_domain: *const ::std::os::raw::c_char,
+        log_level: *const ::std::os::raw::c_char,
+        message: *const ::std::os::raw::c_char,
+        fatal: mono_bool,
+        user_data: *mut ::std::os::raw::c_void,
+    ),
+>;
+extern "C" {
+    pub fn mono_trace_set_level_string(value: *const ::std::os::raw::c_char);
+}
+extern "C" {
+    pub fn mono_trace_set_mask_string(value: *const ::std::os::raw::c_char);
+}
+extern "C" {
+    pub fn mono_trace_set_log_handler(
+        callback: MonoLogCallback,
+        user_data: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn mono_trace_set_print_handler(callback: MonoPrintCallback);
+}
+extern "C" {
+    pub fn mono_trace_set_printerr_handler(callback: MonoPrintCallback);
+}
 pub type __builtin_va_list = [__va_list_tag; 1usize];
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
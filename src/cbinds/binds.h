@@ -6,3 +6,5 @@
 #include <mono-2.0/mono/metadata/class.h>
 #include <mono-2.0/mono/metadata/profiler.h>
 #include <mono-2.0/mono/metadata/mono-debug.h>
+#include <mono-2.0/mono/metadata/threads.h>
+#include <mono-2.0/mono/utils/mono-logger.h>
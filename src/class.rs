@@ -1,9 +1,12 @@
 use crate::binds::MonoClass;
-use crate::tupleutilis::CompareClasses;
-use crate::{Image, InteropSend, Method, ObjectTrait};
+use crate::tupleutilis::{CompareClasses, TupleToFFIPtrs};
+use crate::{Image, InteropSend, Method, ObjectTrait, Type, TypeNameFormat};
 use core::ffi::c_void;
-use std::ffi::CString;
-use std::fmt::{Debug, Formatter};
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::fmt::{Debug, Display, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::RwLock;
 ///  Safe representation of a managed class.(eg. System.Int64, System.Object, etc.);
 #[derive(Eq, Copy, Clone)]
 pub struct Class {
@@ -13,7 +16,23 @@ impl Debug for Class {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let name = self.get_name();
         let namespace = self.get_namespace();
-        write!(f, "Class{{namespace:\"{namespace}\",name:\"{name}\"}}")
+        let image = self.get_image().get_name();
+        write!(
+            f,
+            "Class{{namespace:\"{namespace}\",name:\"{name}\",image:\"{image}\"}}"
+        )
+    }
+}
+/// Formats class as its full name(`Namespace.TypeName`, or just `TypeName` for classes in the global namespace).
+impl Display for Class {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = self.get_name();
+        let namespace = self.get_namespace();
+        if namespace.is_empty() {
+            write!(f, "{name}")
+        } else {
+            write!(f, "{namespace}.{name}")
+        }
     }
 }
 impl Class {
@@ -49,8 +68,27 @@ impl Class {
     /// ```
     #[must_use]
     pub fn from_name(image: &crate::image::Image, namespace: &str, name: &str) -> Option<Self> {
-        let cstr_nspace = CString::new(namespace).expect(crate::STR2CSTR_ERR);
-        let cstr_name = CString::new(name).expect(crate::STR2CSTR_ERR);
+        Self::try_from_name(image, namespace, name).ok()
+    }
+    /// Returns class named *name* in *namespace* in image *image*, or an error describing why it could not be found.
+    /// # Errors
+    /// Returns [`ClassLookupError::InteriorNul`] if *namespace* or *name* contain a null byte, and [`ClassLookupError::NotFound`] if *image* does not contain such class.
+    /// # Example
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// # let some_image = Assembly::assembly_loaded("mscorlib").expect("Assembly mscorlib not loaded, could not get System.Type class!").get_image();
+    /// match Class::try_from_name(&some_image,"System","Type"){
+    ///     Ok(class)=>println!("Found {class}"),
+    ///     Err(err)=>eprintln!("Could not find class:{err}"),
+    /// }
+    /// ```
+    pub fn try_from_name(
+        image: &crate::image::Image,
+        namespace: &str,
+        name: &str,
+    ) -> Result<Self, ClassLookupError> {
+        let cstr_nspace = CString::new(namespace).map_err(|_| ClassLookupError::InteriorNul)?;
+        let cstr_name = CString::new(name).map_err(|_| ClassLookupError::InteriorNul)?;
         let res = unsafe {
             crate::binds::mono_class_from_name(
                 image.get_ptr(),
@@ -58,7 +96,7 @@ impl Class {
                 cstr_name.as_ptr(),
             )
         };
-        unsafe { Self::from_ptr(res) }
+        unsafe { Self::from_ptr(res) }.ok_or(ClassLookupError::NotFound)
     }
     /// Returns class named *name* in *namespace* in image *image*. It is case sensitive.
     ///
@@ -123,16 +161,11 @@ impl Class {
     /// Returns name of this class
     #[must_use]
     pub fn get_name(&self) -> String {
-        let cstr = unsafe {
-            CString::from_raw(crate::binds::mono_class_get_name(self.class_ptr) as *mut i8)
-        };
-        let res = cstr
+        // Name is owned by the runtime, so it must not be freed.
+        unsafe { CStr::from_ptr(crate::binds::mono_class_get_name(self.class_ptr)) }
             .to_str()
             .expect("Could not covert CString to String!")
-            .to_owned();
-        //pointer does not have to be released
-        let _ = cstr.into_raw();
-        res
+            .to_owned()
     }
     /* TODO: Change get_ctos to include new funcion generic arguments
     ///Gets all of the constuctors of this class. **Does not get parent class construtors!**
@@ -175,28 +208,61 @@ impl Class {
     /// Gets a [`Vec`] containing all interfaces this class implements.
     #[must_use]
     pub fn get_interfaces(&self) -> Vec<Self> {
-        let mut gptr = std::ptr::null_mut::<i32>();
+        let mut gptr = std::ptr::null_mut::<std::os::raw::c_void>();
         let mut res = Vec::new();
         while let Some(class) = unsafe {
             Self::from_ptr(crate::binds::mono_class_get_interfaces(
                 self.class_ptr,
-                std::ptr::addr_of_mut!(gptr).cast::<*mut std::os::raw::c_void>(),
+                std::ptr::addr_of_mut!(gptr),
             ))
         } {
             res.push(class);
         }
         res
     }
+    /// Returns instances of custom attributes this class is decorated with. If *`attr_class`* is [`Some`], returns only attributes of that class(or classes deriving from it).
+    /// Fields and properties of returned attributes can then be read like of any other [`Object`].
+    /// # Example
+    /// ## C#
+    ///```csharp
+    /// [Plugin("some plugin")]
+    /// class SomePlugin{}
+    ///```
+    /// ## Rust
+    ///```no_run
+    /// # use wrapped_mono::*;
+    /// # let plugin_class:Class = todo!();
+    /// # let plugin_attribute_class:Class = todo!();
+    /// let plugin_attributes = plugin_class.get_custom_attributes(Some(&plugin_attribute_class));
+    ///```
+    #[must_use]
+    pub fn get_custom_attributes(&self, attr_class: Option<&Self>) -> Vec<Object> {
+        unsafe {
+            construct_custom_attrs(
+                crate::binds::mono_custom_attrs_from_class(self.class_ptr),
+                attr_class,
+            )
+        }
+    }
     /// Gets namespace this class is in, or "" string if it is not in any namespace.
     #[must_use]
     pub fn get_namespace(&self) -> String {
-        let cstr = unsafe {
-            CString::from_raw(crate::binds::mono_class_get_namespace(self.class_ptr) as *mut i8)
-        };
-        let res = cstr.to_str().expect("Could not create CString!").to_owned();
-        //got const pointer that does not have to be freed, so we release it.
-        let _ = cstr.into_raw();
-        res
+        // Namespace is owned by the runtime, so it must not be freed.
+        unsafe { CStr::from_ptr(crate::binds::mono_class_get_namespace(self.class_ptr)) }
+            .to_str()
+            .expect("Could not create CString!")
+            .to_owned()
+    }
+    /// Returns name of this class formatted according to *format*. Unlike [`Display`], it can produce the names used by the managed reflection API,
+    /// e.g. [`TypeNameFormat::AssemblyQualified`] produces a name accepted by `Type.GetType`.
+    #[must_use]
+    pub fn full_name(&self, format: TypeNameFormat) -> String {
+        self.get_type().get_name_full(format)
+    }
+    /// Returns metadata token of this class. For classes defined in an assembly it is a `TypeDef` token(`0x02xxxxxx`), with the low 24 bits being the row in the `TypeDef` table.
+    #[must_use]
+    pub fn get_type_token(&self) -> u32 {
+        unsafe { crate::binds::mono_class_get_type_token(self.class_ptr) }
     }
     ///Gets class this class is nested in, or [`None`] if it is not nested in any type.
     #[must_use]
@@ -218,6 +284,99 @@ impl Class {
     pub fn get_parent(&self) -> Option<Self> {
         unsafe { Self::from_ptr(crate::binds::mono_class_get_parent(self.class_ptr)) }
     }
+    /// Returns managed `System.Type` object representing this class in *domain*. It can be passed to managed APIs expecting a `System.Type`(e.g. generic factory methods).
+    /// # Example
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// # let domain = Domain::get_current().unwrap();
+    /// let string_type = Class::get_string().get_reflection_type(&domain);
+    /// ```
+    #[must_use]
+    pub fn get_reflection_type(&self, domain: &crate::Domain) -> Object {
+        #[cfg(feature = "referenced_objects")]
+        let marker = crate::gc::gc_unsafe_enter();
+        let res = unsafe {
+            Object::from_ptr(
+                crate::binds::mono_type_get_object(
+                    domain.get_ptr(),
+                    crate::binds::mono_class_get_type(self.class_ptr),
+                )
+                .cast(),
+            )
+        }
+        .expect("Could not get reflection type of a class!");
+        #[cfg(feature = "referenced_objects")]
+        crate::gc::gc_unsafe_exit(marker);
+        res
+    }
+    /// Returns number of generic arguments of this class. For open generic definitions(e.g. `Dictionary<,>`) it is the number of generic parameters,
+    /// and for closed instantiations(e.g. `List<int>`) the number of type arguments. Returns 0 for non-generic classes.
+    #[must_use]
+    pub fn generic_argument_count(&self) -> u32 {
+        let rtype = crate::ReflectionType::from_class(self);
+        let obj = unsafe { Object::from_ptr(rtype.get_ptr()) }
+            .expect("Could not convert ReflectionType to Object!");
+        crate::reflection_type::generic_arguments_len(&obj)
+    }
+    /// Returns number of generic parameters which must be bound to create an instance of this generic type definition(e.g. 2 for `Dictionary<,>`).
+    /// Unlike [`Self::generic_argument_count`], returns 0 for closed instantiations(e.g. `List<int>`), as well as for non-generic classes.
+    #[must_use]
+    pub fn generic_param_count(&self) -> u32 {
+        let rtype = crate::ReflectionType::from_class(self);
+        let obj = unsafe { Object::from_ptr(rtype.get_ptr()) }
+            .expect("Could not convert ReflectionType to Object!");
+        let is_definition = obj
+            .call("get_IsGenericTypeDefinition", ())
+            .expect("Got an exception while checking if type is a generic type definition!")
+            .expect("Type.IsGenericTypeDefinition returned null!")
+            .unbox::<bool>();
+        if is_definition {
+            crate::reflection_type::generic_arguments_len(&obj)
+        } else {
+            0
+        }
+    }
+    /// Creates a generic instance of this class with type arguments *args*(e.g. `List<int>` from `List<>` and `int`).
+    /// *self* must be a generic type definition(e.g. ``List`1``). Uses `System.Type.MakeGenericType`, so it returns [`None`] if *self* is not a generic type definition,
+    /// number of *args* does not match number of generic parameters or *args* do not satisfy constraints of the generic parameters.
+    /// # Example
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// # let img = Assembly::assembly_loaded("mscorlib").unwrap().get_image();
+    /// let list = Class::from_name(&img,"System.Collections.Generic","List`1").expect("Could not find class");
+    /// let int_list = list.bind_generic_params(&[Class::get_int_32()]).expect("Could not bind generic parameters");
+    /// ```
+    #[must_use]
+    pub fn bind_generic_params(&self, args: &[Self]) -> Option<Self> {
+        use crate::{Array, Dim1D, ReflectionType};
+        if args.len() != self.generic_param_count() as usize {
+            return None;
+        }
+        let domain = crate::Domain::get_current()
+            .expect("Can't bind generic parameters before JIT started.");
+        let mut type_args: Array<Dim1D, ReflectionType> = Array::new(&domain, &[args.len()]);
+        for (index, arg) in args.iter().enumerate() {
+            type_args.set([index], ReflectionType::from_class(arg));
+        }
+        let definition = unsafe { Object::from_ptr(ReflectionType::from_class(self).get_ptr()) }
+            .expect("Could not convert ReflectionType to Object!");
+        // Look up the most derived override, since `MakeGenericType` is virtual.
+        let mut class = Some(definition.get_class());
+        let make_generic = loop {
+            let current = class.expect("Could not find System.Type.MakeGenericType!");
+            if let Some(method) = Method::<(Array<Dim1D, ReflectionType>,)>::get_from_name(
+                &current,
+                "MakeGenericType",
+                1,
+            ) {
+                break method;
+            }
+            class = current.get_parent();
+        };
+        let instance = make_generic.invoke(Some(definition), (type_args,)).ok()??;
+        let instance = unsafe { ReflectionType::from_ptr(instance.get_ptr()) }?;
+        unsafe { Type::from_ptr(instance.get_type_ptr()) }?.get_class()
+    }
     /// Gets number of dimensions of array.
     /// # Constrains
     /// *self* must be an array type, otherwise returns 0.
@@ -230,6 +389,18 @@ impl Class {
     pub fn data_size(&self) -> i32 {
         unsafe { crate::binds::mono_class_data_size(self.class_ptr) }
     }
+    /// Returns size of an instance of this class in bytes. For reference types this includes the [`crate::binds::MonoObject`] header,
+    /// so it is equal to size of an object of this class(see [`crate::ObjectTrait::get_size`]). Unlike [`Self::data_size`], does not include static data.
+    #[must_use]
+    pub fn instance_size(&self) -> i32 {
+        unsafe { crate::binds::mono_class_instance_size(self.class_ptr) }
+    }
+    /// Returns [`Type`] representing this class.
+    #[must_use]
+    pub fn get_type(&self) -> Type {
+        unsafe { Type::from_ptr(crate::binds::mono_class_get_type(self.class_ptr)) }
+            .expect("Could not get type of a class!")
+    }
     /// Get element class of an array. *self* **must** be an array type, otherwise returns *self*.
     #[must_use]
     pub fn get_element_class(&self) -> Self {
@@ -258,8 +429,65 @@ impl Class {
     pub fn is_enum(&self) -> bool {
         (unsafe { crate::binds::mono_class_is_enum(self.class_ptr) } != 0)
     }
-    //TODO: consider implementing mono_class_is_subclass_of(it seems mostly redundant, but it may be useful)
-    //TODO: figure out what exactly mono_class_num_events is supposed to do, and implement it.
+    /// Returns class of the integer type backing enumeration *self*(e.g. `System.Int32` for `enum Color : int`).
+    /// # Panics
+    /// Panics if *self* is not an enumeration type.
+    #[must_use]
+    pub fn get_enum_underlying_class(&self) -> Self {
+        unsafe { Type::from_ptr(crate::binds::mono_class_enum_basetype(self.class_ptr)) }
+            .and_then(|base| base.get_class())
+            .expect("Can't get underlying type of a class which is not an enum!")
+    }
+    /// Checks if *self* derives from class *other*. If *`check_interfaces`* is true, also returns true if *self* implements interface *other*.
+    /// Unlike [`Self::is_assignable_from`], interfaces are only considered when *`check_interfaces`* is set.
+    #[must_use]
+    pub fn is_subclass_of(&self, other: &Self, check_interfaces: bool) -> bool {
+        (unsafe {
+            crate::binds::mono_class_is_subclass_of(
+                self.class_ptr,
+                other.class_ptr,
+                i32::from(check_interfaces),
+            )
+        } != 0)
+    }
+    /// Checks if *self* is abstract. Interfaces are abstract too.
+    #[must_use]
+    pub fn is_abstract(&self) -> bool {
+        const TYPE_ATTRIBUTE_ABSTRACT: u32 = 0x0080;
+        self.get_flags() & TYPE_ATTRIBUTE_ABSTRACT != 0
+    }
+    /// Checks if *self* represents an interface.
+    #[must_use]
+    pub fn is_interface(&self) -> bool {
+        const TYPE_ATTRIBUTE_INTERFACE: u32 = 0x0020;
+        self.get_flags() & TYPE_ATTRIBUTE_INTERFACE != 0
+    }
+    /// Checks if *self* is sealed(can't be derived from). Value types are always sealed.
+    #[must_use]
+    pub fn is_sealed(&self) -> bool {
+        const TYPE_ATTRIBUTE_SEALED: u32 = 0x0100;
+        self.get_flags() & TYPE_ATTRIBUTE_SEALED != 0
+    }
+    /// Checks if *self* is declared public. Nested classes are considered public if they are declared public inside their enclosing class.
+    #[must_use]
+    pub fn is_public(&self) -> bool {
+        const TYPE_ATTRIBUTE_VISIBILITY_MASK: u32 = 0x0007;
+        const TYPE_ATTRIBUTE_PUBLIC: u32 = 0x0001;
+        const TYPE_ATTRIBUTE_NESTED_PUBLIC: u32 = 0x0002;
+        matches!(
+            self.get_flags() & TYPE_ATTRIBUTE_VISIBILITY_MASK,
+            TYPE_ATTRIBUTE_PUBLIC | TYPE_ATTRIBUTE_NESTED_PUBLIC
+        )
+    }
+    // Returns type attribute flags(`TYPE_ATTRIBUTE_*` in ECMA-335).
+    fn get_flags(&self) -> u32 {
+        unsafe { crate::binds::mono_class_get_flags(self.class_ptr) }
+    }
+    /// Gets number of events declared in the class *self*.
+    #[must_use]
+    pub fn num_events(&self) -> i32 {
+        unsafe { crate::binds::mono_class_num_events(self.class_ptr) }
+    }
     /// Gets amount of **static and instance** files of class
     #[must_use]
     pub fn num_fields(&self) -> i32 {
@@ -270,6 +498,33 @@ impl Class {
     pub fn num_methods(&self) -> i32 {
         unsafe { crate::binds::mono_class_num_methods(self.class_ptr) }
     }
+    /// Returns method at *index*(in range `0..self.num_methods()`) of the class *self*, in declaration order. Returns [`None`] if *index* is out of range, or if the method does not accept arguments of type *Args*.
+    /// Methods are not collected into a [`Vec`], so reaching a single method does not require looking up all of them.
+    /// # Example
+    ///```no_run
+    /// # use wrapped_mono::*;
+    /// # fn first(some_class:Class){
+    /// let first:Option<Method<()>> = some_class.get_method_by_index(0);
+    /// # }
+    ///```
+    #[must_use]
+    pub fn get_method_by_index<Args: TupleToFFIPtrs + CompareClasses>(
+        &self,
+        index: u32,
+    ) -> Option<Method<Args>> {
+        // `mono_class_get_method_by_index` is not exported by the runtime, but `mono_class_get_methods` walks the same method table.
+        let mut gptr = std::ptr::null_mut::<std::os::raw::c_void>();
+        let mut ptr = std::ptr::null_mut();
+        for _ in 0..=index {
+            ptr = unsafe {
+                crate::binds::mono_class_get_methods(self.class_ptr, std::ptr::addr_of_mut!(gptr))
+            };
+            if ptr.is_null() {
+                return None;
+            }
+        }
+        unsafe { Method::from_ptr(ptr) }
+    }
     //TODO: expand this description, since it does not seam to be fully clear.
     /// Gets number of properties in the class(getters,setters,indexers)
     #[must_use]
@@ -281,13 +536,17 @@ impl Class {
     pub fn is_valuetype(&self) -> bool {
         (unsafe { crate::binds::mono_class_is_valuetype(self.class_ptr) } != 0)
     }
-    /*
-    TODO:figure out how this function works and fix it.
-    /// Gets size of a value of type *self*
-    pub fn value_size(&self)->i32{
-        return unsafe{crate::binds::mono_class_value_size(self.class_ptr)};
+    /// Gets size and alignment of a value of type *self*, in bytes, as `(size, alignment)`.
+    /// Only meaningful if *self* is a value type(see [`Self::is_valuetype`]). This is size of the value itself, without the object header boxed values have.
+    #[must_use]
+    pub fn value_size(&self) -> (i32, i32) {
+        let mut align: u32 = 0;
+        let size = unsafe {
+            crate::binds::mono_class_value_size(self.class_ptr, std::ptr::addr_of_mut!(align))
+        };
+        #[allow(clippy::cast_possible_wrap)]
+        (size, align as i32)
     }
-    */
     /// Returns [`Class`] representing `System.Object` type.
     #[must_use]
     pub fn get_object() -> Self {
@@ -440,6 +699,17 @@ impl Class {
         }
         res
     }
+    /// Returns an iterator over fields of this class. Fields are looked up one at a time, so iteration can stop early.
+    pub fn fields_iter(&self) -> impl Iterator<Item = ClassField> {
+        let class_ptr = self.class_ptr;
+        let mut gptr = std::ptr::null_mut::<std::os::raw::c_void>();
+        std::iter::from_fn(move || unsafe {
+            ClassField::from_ptr(crate::binds::mono_class_get_fields(
+                class_ptr,
+                std::ptr::addr_of_mut!(gptr),
+            ))
+        })
+    }
     /// Returns field with name *name*
     #[must_use]
     pub fn get_field(&self, name: &str) -> Option<ClassField> {
@@ -456,6 +726,107 @@ impl Class {
         }
         None
     }
+    /// Returns all fields named *name* declared in this class and its parents, starting with the most derived class.
+    /// Unlike [`Self::get_field_from_name`], it also returns fields shadowed by a field with the same name in a derived class.
+    /// # Example
+    /// ## C#
+    ///```csharp
+    /// class Base{
+    ///     public int value;
+    /// }
+    /// class Derived : Base{
+    ///     public static new int value;
+    /// }
+    ///```
+    /// ## Rust
+    ///```no_run
+    /// # use wrapped_mono::*;
+    /// # fn find(derived:Class){
+    /// let fields = derived.get_fields_named("value");
+    /// assert_eq!(fields.len(),2);
+    /// assert!(fields[0].is_static()); // Derived.value
+    /// # }
+    ///```
+    #[must_use]
+    pub fn get_fields_named(&self, name: &str) -> Vec<ClassField> {
+        let mut res = Vec::new();
+        let mut class = Some(*self);
+        while let Some(current) = class {
+            res.extend(
+                current
+                    .fields_iter()
+                    .filter(|field| field.get_name() == name),
+            );
+            class = current.get_parent();
+        }
+        res
+    }
+    /// Returns [`crate::VTable`] of this class in *domain*, holding its static fields. Returns [`None`] if the class could not be loaded(e.g. one of its dependencies is missing).
+    /// Does not run the static constructor of the class, use [`crate::VTable::init_runtime`] for that.
+    #[must_use]
+    pub fn get_vtable(&self, domain: &crate::Domain) -> Option<crate::VTable> {
+        unsafe {
+            crate::VTable::from_ptr(crate::binds::mono_class_vtable(
+                domain.get_ptr(),
+                self.class_ptr,
+            ))
+        }
+    }
+    // Returns initialized vtable of this class in *domain*, used to access static fields.
+    fn get_initialized_vtable(
+        &self,
+        domain: &crate::Domain,
+    ) -> Result<*mut crate::binds::MonoVTable, Exception> {
+        let vtable = self
+            .get_vtable(domain)
+            .unwrap_or_else(|| panic!("Could not get vtable of class `{}`!", self.get_name_sig()));
+        // Static constructor must run before static fields are accessed.
+        vtable.init_runtime()?;
+        Ok(vtable.get_ptr())
+    }
+    /// Gets value of static *field* of this class, as seen by the calling thread. Works with fields marked with `[ThreadStatic]`, for which every thread has its own value.
+    /// # Example
+    /// ## C#
+    ///```csharp
+    /// class SomeClass{
+    ///     [System.ThreadStatic]
+    ///     static int perThread;
+    /// }
+    ///```
+    /// ## Rust
+    ///```no_run
+    /// # use wrapped_mono::*;
+    /// # fn read(some_class:Class){
+    /// let field = some_class.get_field_from_name("perThread").expect("Could not find field!");
+    /// let value:i32 = some_class.get_thread_static_field_value(&field).expect("Static constructor threw an exception!");
+    /// # }
+    ///```
+    /// # Errors
+    /// Returns `System.TypeInitializationException` if the static constructor of the class threw an exception.
+    /// # Panics
+    /// Panics if *field* is not static, or if its type does not match *T*. Type check is skipped if `unsafe_boxing` feature is enabled.
+    pub fn get_thread_static_field_value<T: InteropBox + Copy>(
+        &self,
+        field: &ClassField,
+    ) -> Result<T, Exception> {
+        let domain =
+            crate::Domain::get_current().expect("Can't access static fields before JIT starts!");
+        field.get_static_value(&domain)
+    }
+    /// Sets value of static *field* of this class for the calling thread. Works with fields marked with `[ThreadStatic]`, for which every thread has its own value.
+    /// # Errors
+    /// Returns `System.TypeInitializationException` if the static constructor of the class threw an exception.
+    /// # Panics
+    /// Panics if *field* is not static, or if its type does not match *T*. Type check is skipped if `unsafe_boxing` feature is enabled.
+    pub fn set_thread_static_field_value<T: InteropBox>(
+        &self,
+        field: &ClassField,
+        value: T,
+    ) -> Result<(), Exception> {
+        let domain =
+            crate::Domain::get_current().expect("Can't access static fields before JIT starts!");
+        field.set_static_value(&domain, value)
+    }
     /* TODO: Fix it to use the new method type
     /// Returns all methods of a class
     pub fn get_methods(&self)->Vec<Method>{
@@ -469,6 +840,29 @@ impl Class {
         return res;
     }
     */
+    /// Returns an iterator over methods of this class accepting arguments of type *Args*. Methods with other signatures are skipped.
+    /// Unlike collecting all methods up front, methods are looked up one at a time, so iteration can stop early.
+    /// **Does not include methods of the parent class.**
+    /// # Example
+    ///```no_run
+    /// # use wrapped_mono::*;
+    /// # fn find(some_class:Class){
+    /// let add = some_class.methods_iter::<(i32,)>().find(|method| method.get_name() == "Add");
+    /// # }
+    ///```
+    pub fn methods_iter<Args: TupleToFFIPtrs + CompareClasses>(
+        &self,
+    ) -> impl Iterator<Item = Method<Args>> {
+        let class_ptr = self.class_ptr;
+        let mut gptr = std::ptr::null_mut::<std::os::raw::c_void>();
+        std::iter::from_fn(move || {
+            let ptr = unsafe {
+                crate::binds::mono_class_get_methods(class_ptr, std::ptr::addr_of_mut!(gptr))
+            };
+            (!ptr.is_null()).then_some(ptr)
+        })
+        .filter_map(|ptr| unsafe { Method::from_ptr(ptr) })
+    }
     /// Gets all types nested inside this class.
     #[must_use]
     pub fn get_nested_types(&self) -> Vec<Self> {
@@ -512,6 +906,32 @@ impl Class {
         }
         res
     }
+    /// Returns an iterator over properties of class *self*. Properties are looked up one at a time, so iteration can stop early.
+    pub fn properties_iter(&self) -> impl Iterator<Item = ClassProperty> {
+        let class_ptr = self.class_ptr;
+        let mut gptr = std::ptr::null_mut::<std::os::raw::c_void>();
+        std::iter::from_fn(move || unsafe {
+            ClassProperty::from_ptr(crate::binds::mono_class_get_properties(
+                class_ptr,
+                std::ptr::addr_of_mut!(gptr),
+            ))
+        })
+    }
+    /// Returns all events of class *self*.
+    #[must_use]
+    pub fn get_events(&self) -> Vec<ClassEvent> {
+        let mut gptr = std::ptr::null_mut::<std::os::raw::c_void>();
+        let mut res = Vec::new();
+        while let Some(event) = unsafe {
+            ClassEvent::from_ptr(crate::binds::mono_class_get_events(
+                self.class_ptr,
+                std::ptr::addr_of_mut!(gptr),
+            ))
+        } {
+            res.push(event);
+        }
+        res
+    }
     /// Returns for use in : "NAMESPACE.NAME"
     #[must_use]
     pub fn get_name_sig(&self) -> String {
@@ -528,6 +948,47 @@ impl std::cmp::PartialEq for Class {
         self.class_ptr == other.class_ptr
     }
 }
+// Creates instances of attributes described by *info*, optionally only ones of class *`attr_class`*, and frees *info*.
+pub(crate) unsafe fn construct_custom_attrs(
+    info: *mut crate::binds::MonoCustomAttrInfo,
+    attr_class: Option<&Class>,
+) -> Vec<Object> {
+    if info.is_null() {
+        return Vec::new();
+    }
+    if let Some(attr_class) = attr_class {
+        if crate::binds::mono_custom_attrs_has_attr(info, attr_class.get_ptr()) == 0 {
+            crate::binds::mono_custom_attrs_free(info);
+            return Vec::new();
+        }
+    }
+    #[cfg(feature = "referenced_objects")]
+    let marker = crate::gc::gc_unsafe_enter();
+    // Constructed array is of type `System.Attribute[]`, so its elements are read directly.
+    let attrs = crate::binds::mono_custom_attrs_construct(info);
+    let mut res = Vec::new();
+    if !attrs.is_null() {
+        for i in 0..crate::binds::mono_array_length(attrs) {
+            let attr = *crate::binds::mono_array_addr_with_size(
+                attrs,
+                std::mem::size_of::<*mut crate::binds::MonoObject>() as i32,
+                i,
+            )
+            .cast::<*mut crate::binds::MonoObject>();
+            let Some(attr) = Object::from_ptr(attr) else {
+                continue;
+            };
+            if attr_class.is_some_and(|class| !class.is_assignable_from(&attr.get_class())) {
+                continue;
+            }
+            res.push(attr);
+        }
+    }
+    #[cfg(feature = "referenced_objects")]
+    crate::gc::gc_unsafe_exit(marker);
+    crate::binds::mono_custom_attrs_free(info);
+    res
+}
 use crate::binds::MonoClassField;
 use crate::object::Object;
 /// Representation of a class field. Accessors(getters,setters and indexers) are *not* fields, but properties! For them use [`ClassProperty`]
@@ -562,15 +1023,17 @@ impl ClassField {
     ///```
     #[must_use]
     pub fn get_name(&self) -> String {
-        let cstr = unsafe {
-            std::ffi::CString::from_raw(crate::binds::mono_field_get_name(self.get_ptr()) as *mut i8)
-        };
-        let name = cstr
+        // Name is owned by the runtime, so it must not be freed.
+        unsafe { CStr::from_ptr(crate::binds::mono_field_get_name(self.get_ptr())) }
             .to_str()
             .expect("Could not create String from ptr")
-            .to_owned();
-        drop(cstr);
-        name
+            .to_owned()
+    }
+    /// Returns offset of this field in bytes. For instance fields of reference types the offset is from the start of the object(including the object header),
+    /// so fields declared later in a class with sequential layout have higher offsets.
+    #[must_use]
+    pub fn get_offset(&self) -> u32 {
+        unsafe { crate::binds::mono_field_get_offset(self.get_ptr()) }
     }
     /// Gets metadata(???) tokens of a field. **not** it's value
     #[must_use]
@@ -638,29 +1101,29 @@ impl ClassField {
     /// let some_field_value_object = some_field.set_value_unsafe(&instance_of_some_class,&mut value_to_set as *mut u16 as *mut  std::os::raw::c_void);
     /// ```
     /// # Safety
-    /// *`value_ptr`* pointer must be valid and have correct type.
+    /// *`value_ptr`* pointer must be valid and point to a value of the type of the field. The runtime does not check the type of the value, so a value of a wrong type
+    /// (e.g. a pointer to an object of an unrelated class stored in a reference field) is copied as is and corrupts the field.
+    /// For reference fields *`value_ptr`* must point to the object pointer, not to the object itself. To store references to managed objects use [`Self::set_value_ref`], which checks the type.
     pub unsafe fn set_value_unsafe(&self, obj: &Object, value_ptr: *mut std::os::raw::c_void) {
         crate::binds::mono_field_set_value(obj.get_ptr(), self.get_ptr(), value_ptr);
     }
 }
 use crate::interop::{InteropBox, InteropClass};
 impl ClassField {
-    /// Sets value of a boxable type. WARING: currently there are no checks to ensure value type and field type match.
+    /// Sets value of a boxable type, copying *val* directly into the field.
+    /// # Example
+    ///```no_run
+    /// # use wrapped_mono::*;
+    /// # fn set(some_field:ClassField,instance_of_some_class:&Object){
+    /// some_field.set_value::<i32>(instance_of_some_class,42).expect("Field is not an int!");
+    /// # }
+    ///```
     /// # Errors
-    /// Returns error message if failed.
+    /// Returns error message if field is static, *obj* does not have this field, or type of the field does not match *T*. Type check is skipped if `unsafe_boxing` feature is enabled.
     pub fn set_value<T: InteropBox>(&self, obj: &Object, mut val: T) -> Result<(), String> {
+        self.check_instance(obj)?;
         #[cfg(not(feature = "unsafe_boxing"))]
-        {
-            let object_class = obj.get_class();
-            let target_class = <T as InteropClass>::get_mono_class();
-            if object_class != target_class {
-                return Err(format!(
-                    "Tried setting value of field of type `{}` as `{}` type!",
-                    &object_class.get_name(),
-                    &target_class.get_name()
-                ));
-            }
-        }
+        self.check_type::<T>()?;
         unsafe {
             crate::binds::mono_field_set_value(
                 obj.get_ptr(),
@@ -670,36 +1133,228 @@ impl ClassField {
         }
         Ok(())
     }
-    /// Gets value of a boxable type.
-    /// Returns unboxed value or error message if unboxing failed.
+    /// Gets value of a boxable type, copying it directly out of the field(without boxing it first).
     /// # Errors
-    /// Returns error message if failed.
+    /// Returns error message if field is static, *obj* does not have this field, or type of the field does not match *T*. Type check is skipped if `unsafe_boxing` feature is enabled.
     pub fn get_value<T: InteropBox + std::marker::Copy + InteropClass>(
         &self,
         obj: &Object,
     ) -> Result<T, String> {
-        let dom = obj.get_domain();
-        let obj = unsafe {
-            Object::from_ptr(crate::binds::mono_field_get_value_object(
-                dom.get_ptr(),
-                self.get_ptr(),
+        self.check_instance(obj)?;
+        #[cfg(not(feature = "unsafe_boxing"))]
+        self.check_type::<T>()?;
+        let mut value = std::mem::MaybeUninit::<T>::uninit();
+        unsafe {
+            crate::binds::mono_field_get_value(
                 obj.get_ptr(),
+                self.get_ptr(),
+                value.as_mut_ptr().cast::<c_void>(),
+            );
+            Ok(value.assume_init())
+        }
+    }
+    /// Gets value of static field *self* in *domain*. Runs static constructor of the class first, if it did not run yet.
+    /// # Example
+    /// ## C#
+    ///```csharp
+    /// class SomeClass{
+    ///     static int counter;
+    /// }
+    ///```
+    /// ## Rust
+    ///```no_run
+    /// # use wrapped_mono::*;
+    /// # fn read(some_class:Class,domain:&Domain){
+    /// let counter = some_class.get_field_from_name("counter").expect("Could not find field!");
+    /// let value:i32 = counter.get_static_value(domain).expect("Static constructor threw an exception!");
+    /// # }
+    ///```
+    /// # Errors
+    /// Returns `System.TypeInitializationException` if the static constructor of the class threw an exception.
+    /// # Panics
+    /// Panics if field is not static, or if its type does not match *T*. Type check is skipped if `unsafe_boxing` feature is enabled.
+    pub fn get_static_value<T: InteropBox + Copy>(
+        &self,
+        domain: &crate::Domain,
+    ) -> Result<T, Exception> {
+        self.assert_static_of_type::<T>();
+        let vtable = self.get_parent().get_initialized_vtable(domain)?;
+        let mut value = std::mem::MaybeUninit::<T>::uninit();
+        unsafe {
+            crate::binds::mono_field_static_get_value(
+                vtable,
+                self.get_ptr(),
+                value.as_mut_ptr().cast::<c_void>(),
+            );
+            Ok(value.assume_init())
+        }
+    }
+    /// Sets value of static field *self* in *domain* to *value*. Runs static constructor of the class first, if it did not run yet.
+    /// # Errors
+    /// Returns `System.TypeInitializationException` if the static constructor of the class threw an exception.
+    /// # Panics
+    /// Panics if field is not static, or if its type does not match *T*. Type check is skipped if `unsafe_boxing` feature is enabled.
+    pub fn set_static_value<T: InteropBox>(
+        &self,
+        domain: &crate::Domain,
+        mut value: T,
+    ) -> Result<(), Exception> {
+        self.assert_static_of_type::<T>();
+        let vtable = self.get_parent().get_initialized_vtable(domain)?;
+        unsafe {
+            crate::binds::mono_field_static_set_value(
+                vtable,
+                self.get_ptr(),
+                std::ptr::addr_of_mut!(value).cast::<c_void>(),
+            );
+        }
+        Ok(())
+    }
+    /// Returns [`Class`] of values stored in this field.
+    #[must_use]
+    pub fn get_type(&self) -> Class {
+        unsafe {
+            Class::from_ptr(crate::binds::mono_class_from_mono_type(
+                crate::binds::mono_field_get_type(self.get_ptr()),
+            ))
+        }
+        .expect("Could not get type of a field!")
+    }
+    /// Checks if this field is static. Values of static fields are not stored in instances, but in the vtable of the class.
+    #[must_use]
+    pub fn is_static(&self) -> bool {
+        const FIELD_ATTRIBUTE_STATIC: u32 = 0x0010;
+        self.get_flags() & FIELD_ATTRIBUTE_STATIC != 0
+    }
+    /// Checks if this field is public.
+    #[must_use]
+    pub fn is_public(&self) -> bool {
+        const FIELD_ATTRIBUTE_FIELD_ACCESS_MASK: u32 = 0x0007;
+        const FIELD_ATTRIBUTE_PUBLIC: u32 = 0x0006;
+        self.get_flags() & FIELD_ATTRIBUTE_FIELD_ACCESS_MASK == FIELD_ATTRIBUTE_PUBLIC
+    }
+    // Returns field attribute flags(`FIELD_ATTRIBUTE_*` in ECMA-335).
+    fn get_flags(&self) -> u32 {
+        unsafe { crate::binds::mono_field_get_flags(self.get_ptr()) }
+    }
+    // Checks that this is an instance field of *obj*. Otherwise the runtime would access memory outside of *obj*(or, for static fields, not access it at all).
+    fn check_instance(&self, obj: &Object) -> Result<(), String> {
+        if self.is_static() {
+            return Err(format!("Field `{}` is static!", self.get_name()));
+        }
+        let obj_class = obj.get_class();
+        if !self.get_parent().is_assignable_from(&obj_class) {
+            return Err(format!(
+                "Object of type `{}` has no field `{}`!",
+                &obj_class.get_name(),
+                self.get_name()
+            ));
+        }
+        Ok(())
+    }
+    // Checks that this field holds values of type *T*.
+    #[cfg(not(feature = "unsafe_boxing"))]
+    fn check_type<T: InteropClass>(&self) -> Result<(), String> {
+        let field_class = self.get_type();
+        let target_class = <T as InteropClass>::get_mono_class();
+        if field_class == target_class {
+            Ok(())
+        } else {
+            Err(format!(
+                "Tried accessing field of type `{}` as `{}` type!",
+                &field_class.get_name(),
+                &target_class.get_name()
             ))
         }
-        .expect("Cant unbox null as value type");
+    }
+    // Checks that field is static and, unless `unsafe_boxing` is enabled, that it holds values of type *T*.
+    fn assert_static_of_type<T: InteropClass>(&self) {
+        assert!(
+            self.is_static(),
+            "Field `{}` is not static!",
+            self.get_name()
+        );
         #[cfg(not(feature = "unsafe_boxing"))]
-        {
-            let object_class = obj.get_class();
-            let target_class = <T as InteropClass>::get_mono_class();
-            if object_class != target_class {
-                return Err(format!(
-                    "Tried getting value of field of type `{}` as `{}` type!",
-                    &object_class.get_name(),
-                    &target_class.get_name()
-                ));
-            }
+        if let Err(msg) = self.check_type::<T>() {
+            panic!("{msg}");
+        }
+    }
+    /// Stores reference to *value* in field *self* of *obj*. Uses the GC write barrier, so the garbage collector is aware of the new reference.
+    /// # Example
+    ///```no_run
+    /// # use wrapped_mono::*;
+    /// # fn set(some_field:ClassField,instance_of_some_class:&Object,domain:&Domain){
+    /// let value:Object = MString::new(domain,"some text").cast().expect("String is an object!");
+    /// some_field.set_value_ref(instance_of_some_class,&value).expect("Field can't hold a string!");
+    /// # }
+    ///```
+    /// # Errors
+    /// Returns error message if field is static, *obj* does not have this field, field holds a value type, or *value* can't be assigned to it.
+    pub fn set_value_ref(&self, obj: &Object, value: &Object) -> Result<(), String> {
+        self.check_instance(obj)?;
+        let field_class = self.get_type();
+        if field_class.is_valuetype() {
+            return Err(format!(
+                "Field `{}` can't hold a reference to an object!",
+                self.get_name()
+            ));
+        }
+        let value_class = value.get_class();
+        if !field_class.is_assignable_from(&value_class) {
+            return Err(format!(
+                "Tried storing object of type `{}` in field of type `{}`!",
+                &value_class.get_name(),
+                &field_class.get_name()
+            ));
+        }
+        #[cfg(feature = "referenced_objects")]
+        let marker = crate::gc::gc_unsafe_enter();
+        unsafe {
+            let obj_ptr = obj.get_ptr();
+            // Offset of an instance field includes the object header.
+            let field_ptr = obj_ptr
+                .cast::<u8>()
+                .add(crate::binds::mono_field_get_offset(self.get_ptr()) as usize);
+            crate::binds::mono_gc_wbarrier_set_field(obj_ptr, field_ptr.cast(), value.get_ptr());
+        }
+        #[cfg(feature = "referenced_objects")]
+        crate::gc::gc_unsafe_exit(marker);
+        Ok(())
+    }
+    /// Reads reference stored in field *self* of *obj*, without boxing. Returns [`None`] if the field holds null, is static, is not a field of *obj*, or holds a value type(use [`Self::get_value_object`] for those).
+    /// # Example
+    /// ## C#
+    ///```csharp
+    /// class SomeClass{
+    ///     string someField = "hi";
+    /// }
+    ///```
+    /// ## Rust
+    ///```no_run
+    /// # use wrapped_mono::*;
+    /// # fn get(some_field:ClassField,instance_of_some_class:&Object){
+    /// let text:MString = some_field.get_reference_value(instance_of_some_class).expect("Field is null!").cast().expect("Field does not hold a string!");
+    /// # }
+    ///```
+    #[must_use]
+    pub fn get_reference_value(&self, obj: &Object) -> Option<Object> {
+        if self.check_instance(obj).is_err() || self.get_type().is_valuetype() {
+            return None;
         }
-        Ok(obj.unbox::<T>())
+        let mut value: *mut crate::binds::MonoObject = std::ptr::null_mut();
+        #[cfg(feature = "referenced_objects")]
+        let marker = crate::gc::gc_unsafe_enter();
+        let res = unsafe {
+            crate::binds::mono_field_get_value(
+                obj.get_ptr(),
+                self.get_ptr(),
+                std::ptr::addr_of_mut!(value).cast(),
+            );
+            Object::from_ptr(value)
+        };
+        #[cfg(feature = "referenced_objects")]
+        crate::gc::gc_unsafe_exit(marker);
+        res
     }
     /// Sets value of field *self* on *object* to *value*
     pub fn set_value_object(&self, obj: &Object, value: &Object) {
@@ -712,6 +1367,12 @@ impl ClassField {
         }
     }
 }
+impl std::cmp::PartialEq for ClassField {
+    fn eq(&self, other: &Self) -> bool {
+        self.cf_ptr == other.cf_ptr
+    }
+}
+impl Eq for ClassField {}
 use crate::binds::MonoProperty;
 use crate::Exception;
 use core::ptr::null_mut;
@@ -797,17 +1458,65 @@ impl ClassProperty {
             Err(e)
         }
     }
+    /// Gets value of property *self* of *obj*(pass [`None`] if static). For boxable types the value is in boxed form.
+    /// # Example
+    /// ## C#
+    ///```csharp
+    /// class SomeClass{
+    ///     public int SomeProperty{get;set;}
+    /// }
+    ///```
+    /// ## Rust
+    ///```no_run
+    /// # use wrapped_mono::*;
+    /// # fn read(some_class:Class,instance_of_some_class:&Object){
+    /// let property = some_class.get_property_from_name("SomeProperty").expect("Could not find property!");
+    /// let value = property.get_value(Some(instance_of_some_class)).expect("Getter threw an exception!").expect("Got null!").unbox::<i32>();
+    /// # }
+    /// ```
+    /// # Errors
+    /// Returns an exception if it was thrown by the getter.
+    pub fn get_value(&self, obj: Option<&Object>) -> Result<Option<Object>, Exception> {
+        unsafe { self.get(obj.cloned(), &[]) }
+    }
+    /// Sets value of property *self* of *obj*(pass [`None`] if static) to *value*. The setter is called with [`Method::invoke`], so *value* is checked against its parameter.
+    /// # Errors
+    /// Returns an exception if it was thrown by the setter, or `System.ArgumentException` if the property has no setter accepting *T*.
+    pub fn set_value<T: InteropSend + InteropClass>(
+        &self,
+        obj: Option<&Object>,
+        value: T,
+    ) -> Result<(), Exception> {
+        let setter = self.get_set_method::<T>().ok_or_else(|| {
+            Exception::argument_exception(
+                "value",
+                &format!(
+                    "Property `{}` has no setter accepting `{}`!",
+                    self.get_name(),
+                    T::get_mono_class().get_name()
+                ),
+            )
+        })?;
+        setter.invoke(obj.cloned(), (value,)).map(|_| ())
+    }
+    /// Gets name of this property.
+    #[must_use]
+    pub fn get_name(&self) -> String {
+        // Name is owned by the runtime, so it must not be freed.
+        unsafe { CStr::from_ptr(crate::binds::mono_property_get_name(self.prop_ptr)) }
+            .to_str()
+            .expect("Could not create String from ptr")
+            .to_owned()
+    }
     /// Gets getter method of this property.
     #[must_use]
-    pub fn get_get_method<T: InteropSend + InteropClass>(&self) -> Option<Method<(T,)>> {
+    pub fn get_get_method(&self) -> Option<Method<()>> {
         unsafe { Method::from_ptr(crate::binds::mono_property_get_get_method(self.prop_ptr)) }
     }
     /// Gets setter method of this property.
     #[must_use]
-    pub fn get_set_method<T: InteropSend + CompareClasses + InteropClass>(
-        &self,
-    ) -> Option<Method<(T,)>> {
-        unsafe { Method::from_ptr(crate::binds::mono_property_get_get_method(self.prop_ptr)) }
+    pub fn get_set_method<T: InteropSend + InteropClass>(&self) -> Option<Method<(T,)>> {
+        unsafe { Method::from_ptr(crate::binds::mono_property_get_set_method(self.prop_ptr)) }
     }
     /// Gets class this property is attached to.
     #[must_use]
@@ -815,9 +1524,133 @@ impl ClassProperty {
         unsafe { Class::from_ptr(crate::binds::mono_property_get_parent(self.prop_ptr)) }
             .expect("Cold not get class this property is attached to")
     }
-    //TODO:mono_property_get_name
 }
 use crate::assembly::Assembly;
+use crate::binds::MonoEvent;
+/// Representation of a managed event declared by a class.
+pub struct ClassEvent {
+    event_ptr: *mut MonoEvent,
+}
+impl ClassEvent {
+    /// Creates new [`ClassEvent`] from a *mut [`MonoEvent`].
+    /// # Safety
+    /// The *ptr* must be either a valid pointer to [`MonoEvent`] or null.
+    pub unsafe fn from_ptr(ptr: *mut MonoEvent) -> Option<Self> {
+        if ptr.is_null() {
+            None
+        } else {
+            Some(Self { event_ptr: ptr })
+        }
+    }
+    /// Gets internal [`MonoEvent`] pointer.
+    #[must_use]
+    pub fn get_ptr(&self) -> *mut MonoEvent {
+        self.event_ptr
+    }
+    /// Gets name of this event.
+    #[must_use]
+    pub fn get_name(&self) -> String {
+        // Name is owned by the runtime, so it must not be freed.
+        unsafe { CStr::from_ptr(crate::binds::mono_event_get_name(self.event_ptr)) }
+            .to_str()
+            .expect("Could not create String from ptr")
+            .to_owned()
+    }
+    /// Gets method subscribing a handler of type *T* to this event(`add` accessor).
+    #[must_use]
+    pub fn get_add_method<T: InteropSend + InteropClass>(&self) -> Option<Method<(T,)>> {
+        unsafe { Method::from_ptr(crate::binds::mono_event_get_add_method(self.event_ptr)) }
+    }
+    /// Gets method unsubscribing a handler of type *T* from this event(`remove` accessor).
+    #[must_use]
+    pub fn get_remove_method<T: InteropSend + InteropClass>(&self) -> Option<Method<(T,)>> {
+        unsafe { Method::from_ptr(crate::binds::mono_event_get_remove_method(self.event_ptr)) }
+    }
+    /// Gets class this event is declared in.
+    #[must_use]
+    pub fn get_parent(&self) -> Class {
+        unsafe { Class::from_ptr(crate::binds::mono_event_get_parent(self.event_ptr)) }
+            .expect("Could not get class this event is declared in")
+    }
+}
+// Number of times images could have been unloaded(by unloading a domain, or closing an assembly or image).
+static UNLOAD_COUNT: AtomicUsize = AtomicUsize::new(0);
+// Invalidates all [`ClassCache`]s. Called whenever images may be unloaded, since the runtime may reuse their memory for other images.
+pub(crate) fn invalidate_class_caches() {
+    UNLOAD_COUNT.fetch_add(1, Ordering::Release);
+}
+// Classes keyed by pointer to their image, namespace and name, together with `UNLOAD_COUNT` at the time they were cached.
+#[derive(Default)]
+struct CachedClasses {
+    unload_count: usize,
+    images: HashMap<usize, HashMap<String, HashMap<String, Class>>>,
+}
+/// Thread-safe cache of classes looked up by name, avoiding repeated metadata lookups of the same class(e.g. in hot dispatch loops).
+/// Classes are keyed by the image they are in, so one cache can be safely shared across domains.
+/// Cached classes are dropped whenever images may have been unloaded([`crate::Domain::unload`], [`crate::Assembly::close`] or [`Image::close`]),
+/// since the runtime may reuse the memory of an unloaded image for a different one.
+/// # Example
+/// ```no_run
+/// # use wrapped_mono::*;
+/// # let image:Image = todo!();
+/// let cache = ClassCache::new();
+/// for _ in 0..1000{
+///     let class = cache.from_name(&image,"SomeNamespace","SomeClass").expect("Could not find class!");
+/// }
+/// ```
+#[derive(Default)]
+pub struct ClassCache {
+    classes: RwLock<CachedClasses>,
+}
+impl ClassCache {
+    /// Creates a new, empty [`ClassCache`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Returns class named *name* in *namespace* in *image*, looking it up with [`Class::from_name`] only if it is not already cached.
+    /// Classes which could not be found are not cached.
+    #[must_use]
+    pub fn from_name(&self, image: &Image, namespace: &str, name: &str) -> Option<Class> {
+        let image_key = image.get_ptr() as usize;
+        let unload_count = UNLOAD_COUNT.load(Ordering::Acquire);
+        {
+            let cached = self.classes.read().expect("Class cache lock poisoned!");
+            if cached.unload_count == unload_count {
+                if let Some(class) = cached
+                    .images
+                    .get(&image_key)
+                    .and_then(|namespaces| namespaces.get(namespace))
+                    .and_then(|classes| classes.get(name))
+                {
+                    return Some(*class);
+                }
+            }
+        }
+        let class = Class::from_name(image, namespace, name)?;
+        let mut cached = self.classes.write().expect("Class cache lock poisoned!");
+        if cached.unload_count != unload_count {
+            cached.images.clear();
+            cached.unload_count = unload_count;
+        }
+        cached
+            .images
+            .entry(image_key)
+            .or_default()
+            .entry(namespace.to_owned())
+            .or_default()
+            .insert(name.to_owned(), class);
+        Some(class)
+    }
+    /// Removes all cached classes.
+    pub fn clear(&self) {
+        self.classes
+            .write()
+            .expect("Class cache lock poisoned!")
+            .images
+            .clear();
+    }
+}
 use lazy_static::lazy_static;
 lazy_static! {
     static ref DELEGATE: Class = {
@@ -831,3 +1664,20 @@ lazy_static! {
 // Sharing Classes between thread is safe
 unsafe impl Sync for Class {}
 unsafe impl Send for Class {}
+/// Reason why [`Class::try_from_name`] could not find a class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClassLookupError {
+    /// Namespace or name contained a null byte, so it could not be passed to the runtime.
+    InteriorNul,
+    /// Image does not contain a class with such namespace and name.
+    NotFound,
+}
+impl Display for ClassLookupError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InteriorNul => write!(f, "namespace or name contains a null byte"),
+            Self::NotFound => write!(f, "class not found in image"),
+        }
+    }
+}
+impl std::error::Error for ClassLookupError {}
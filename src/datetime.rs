@@ -0,0 +1,100 @@
+use crate::interop::{InteropBox, InteropClass, InteropReceive, InteropSend};
+use crate::Class;
+use std::sync::LazyLock;
+use std::time::{Duration, SystemTime};
+/// Number of ticks(100 nanosecond intervals) between 0001-01-01 and the unix epoch.
+const UNIX_EPOCH_TICKS: i64 = 621_355_968_000_000_000;
+const TICKS_PER_SECOND: i64 = 10_000_000;
+/// Ticks of `DateTime.MaxValue`(9999-12-31 23:59:59.9999999).
+const MAX_TICKS: i64 = 3_155_378_975_999_999_999;
+/// Mask of bits of `System.DateTime` storing ticks. Two highest bits store `DateTimeKind`.
+const TICKS_MASK: u64 = 0x3FFF_FFFF_FFFF_FFFF;
+/// `DateTimeKind.Utc` stored in the two highest bits.
+const KIND_UTC: u64 = 0x4000_0000_0000_0000;
+/// Representation of the managed `System.DateTime` struct. Passed to and from managed code by value, and can be boxed and unboxed.
+/// Conversions to and from [`SystemTime`] assume the time is in UTC.
+/// # Example
+/// ```no_run
+/// # use wrapped_mono::*;
+/// #[invokable]
+/// fn log_timestamp(time:DateTime){
+///     println!("Got timestamp {:?}",time.to_system_time());
+/// }
+/// ```
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DateTime {
+    data: u64,
+}
+impl DateTime {
+    /// Returns number of ticks(100 nanosecond intervals) since 0001-01-01 00:00:00, like the managed `DateTime.Ticks` property.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub const fn ticks(&self) -> i64 {
+        (self.data & TICKS_MASK) as i64
+    }
+    /// Creates a UTC [`DateTime`] from number of *ticks*(100 nanosecond intervals) since 0001-01-01 00:00:00.
+    /// # Panics
+    /// Panics if *ticks* is negative or larger than ticks of `DateTime.MaxValue`.
+    #[must_use]
+    #[allow(clippy::cast_sign_loss)]
+    pub const fn from_ticks(ticks: i64) -> Self {
+        assert!(
+            ticks >= 0 && ticks <= MAX_TICKS,
+            "Ticks out of range of DateTime!"
+        );
+        Self {
+            data: ticks as u64 | KIND_UTC,
+        }
+    }
+    /// Converts this [`DateTime`] to [`SystemTime`], assuming it is in UTC.
+    #[must_use]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn to_system_time(&self) -> SystemTime {
+        let since_epoch = self.ticks() - UNIX_EPOCH_TICKS;
+        let duration = Duration::new(
+            since_epoch.unsigned_abs() / TICKS_PER_SECOND as u64,
+            (since_epoch.unsigned_abs() % TICKS_PER_SECOND as u64) as u32 * 100,
+        );
+        if since_epoch >= 0 {
+            SystemTime::UNIX_EPOCH + duration
+        } else {
+            SystemTime::UNIX_EPOCH - duration
+        }
+    }
+    /// Converts *time* to a UTC [`DateTime`]. Precision is reduced to ticks(100 nanoseconds).
+    /// # Panics
+    /// Panics if *time* can't be represented by `System.DateTime`(is before year 1 or after year 9999).
+    #[must_use]
+    pub fn from_system_time(time: SystemTime) -> Self {
+        let to_ticks = |duration: Duration| {
+            i64::try_from(duration.as_nanos() / 100).expect("Time out of range of DateTime!")
+        };
+        let ticks = match time.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(after) => UNIX_EPOCH_TICKS + to_ticks(after),
+            Err(before) => UNIX_EPOCH_TICKS - to_ticks(before.duration()),
+        };
+        Self::from_ticks(ticks)
+    }
+}
+impl InteropReceive for DateTime {
+    type SourceType = Self;
+    fn get_rust_rep(mono_arg: Self::SourceType) -> Self {
+        mono_arg
+    }
+}
+// `System.DateTime` is a struct containing only a 64 bit integer, like `DateTime`.
+unsafe impl InteropSend for DateTime {}
+impl InteropClass for DateTime {
+    fn get_mono_class() -> Class {
+        *DATE_TIME_CLASS
+    }
+}
+impl InteropBox for DateTime {}
+static DATE_TIME_CLASS: LazyLock<Class> = LazyLock::new(|| {
+    let img = crate::Assembly::assembly_loaded("mscorlib")
+        .expect("Assembly mscorlib not loaded, could not get System.DateTime class!")
+        .get_image();
+    Class::from_name_case(&img, "System", "DateTime")
+        .expect("Could not get System.DateTime class form mscorlib!")
+});
@@ -1,434 +1,244 @@
-use crate::binds::{MonoDelegate, MonoMethod};
-use crate::binds::{MonoException, MonoObject};
+use crate::binds::{MonoMethod, MonoObject};
 use crate::gc::{gc_unsafe_enter, gc_unsafe_exit, GCHandle};
-use crate::tupleutilis::{CompareClasses, TupleToPtrs};
-#[allow(unused_imports)] // for docs
-use crate::Method;
-use crate::ObjectTrait;
-use crate::{Class, Domain, Exception, InteropClass, InteropReceive, InteropSend, MString, Object};
+use crate::interop::{InteropClass, InteropSend};
+use crate::tupleutilis::{CompareClasses, TupleToFFIPtrs};
+use crate::{Class, Exception, Method, Object, ObjectTrait, RootedObject};
 use core::ptr::null_mut;
 use std::ffi::c_void;
-use std::marker::PhantomData;
-/// A safe representation of a delegate.
-/// Args - a Tuple type made from types of all arguments accepted by this particular delegate
-/// # Safety
-/// ## Type Mismatch
-/// When a delegate is received from mono runtime it's argument types are checked, but those checks are not yet made for a delegate with either 1 or no arguments.
-/// This is not a bug, it only means that safety features will not catch some of your errors(wrong types provided by the user of this crate). As long as the signature on the Rust side matches the signature on the C#/F# side, you will never encounter this problem.
-/// ## All arguments **must** implement InteropClass!
-/// While this is not enforced jet because of limitations of the API(no support for C# tuples), **IT IS STILL NECESSARY**. Ignoring this warning and using Delegates with arguments not implementing InteropClass **will lead to crashes and undefined behaviour**. Before filing bug reports, check that all arguments of your function implement InteropClass.
-pub struct Delegate<Args: InteropSend> {
+/// Argument which can be passed to [`Delegate::invoke`]. Implemented for all types implementing [`InteropSend`] and [`InteropClass`].
+pub trait InvokeArg {
+    /// Returns pointer passed to the runtime as this argument: pointer to the value for value types, and pointer to the object for reference types.
+    fn get_invoke_ptr(&self) -> *mut c_void;
+    /// Checks if [`Self::get_invoke_ptr`] returns pointer to a managed object(e.g. a managed string created from a [`String`]), which must be kept alive until the call returns.
+    fn is_object_ptr(&self) -> bool;
+    /// Returns managed class of this argument.
+    fn get_arg_class(&self) -> Class;
+}
+impl<T: InteropSend + InteropClass> InvokeArg for T {
+    fn get_arg_class(&self) -> Class {
+        T::get_mono_class()
+    }
+    fn get_invoke_ptr(&self) -> *mut c_void {
+        if T::is_object_arg() {
+            // Getting a pointer to an object does not modify it, so it can be done on a copy, as long as the copy is not dropped.
+            let mut copy = std::mem::ManuallyDrop::new(unsafe { std::ptr::read(self) });
+            copy.get_ffi_ptr()
+        } else {
+            (self as *const T).cast_mut().cast()
+        }
+    }
+    fn is_object_ptr(&self) -> bool {
+        T::is_object_arg()
+    }
+}
+// Checks if *args* can be passed to parameters of classes *params*.
+pub(crate) fn args_match(params: &[Class], args: &[&dyn InvokeArg]) -> bool {
+    params.len() == args.len()
+        && params
+            .iter()
+            .zip(args)
+            .all(|(param, arg)| param.is_assignable_from(&arg.get_arg_class()))
+}
+// Converts *args* to pointers passed to the runtime. Objects created while converting the arguments(e.g. managed strings) are referenced only by those pointers,
+// which are stored on the heap and not seen by the GC, so they are rooted until the returned roots are dropped, after the call returns.
+pub(crate) fn invoke_ptrs(args: &[&dyn InvokeArg]) -> (Vec<*mut c_void>, Vec<RootedObject>) {
+    let mut roots = Vec::new();
+    let params = args
+        .iter()
+        .map(|arg| {
+            let ptr = arg.get_invoke_ptr();
+            if arg.is_object_ptr() {
+                roots.extend(unsafe { RootedObject::from_ptr(ptr.cast()) });
+            }
+            ptr
+        })
+        .collect();
+    (params, roots)
+}
+/// List of arguments built one by one, which can be passed to [`Method::invoke_args`] and [`Delegate::invoke`].
+/// Value types are passed to the runtime by pointer, without boxing. Strings are converted to managed strings when the method is invoked, and kept alive until it returns.
+/// # Example
+/// ```no_run
+/// # use wrapped_mono::*;
+/// # let method:Method<(i32,String,f64)> = todo!();
+/// let args = MethodArgs::new().add(42_i32).add("hello").add(3.14_f64);
+/// let res = method.invoke_args(None,&args).expect("Got an exception!");
+/// ```
+#[derive(Default)]
+pub struct MethodArgs {
+    args: Vec<Box<dyn InvokeArg>>,
+}
+impl MethodArgs {
+    /// Creates an empty argument list.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Appends *arg* to the argument list.
+    #[must_use]
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(mut self, arg: impl InvokeArg + 'static) -> Self {
+        self.args.push(Box::new(arg));
+        self
+    }
+    /// Returns number of arguments in the list.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.args.len()
+    }
+    /// Checks if the argument list is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty()
+    }
+    /// Returns references to the arguments, in the form accepted by [`Delegate::invoke`].
+    #[must_use]
+    pub fn as_refs(&self) -> Vec<&dyn InvokeArg> {
+        self.args.iter().map(AsRef::as_ref).collect()
+    }
+}
+/// Safe representation of a managed delegate(an [`Object`] of a type derived from **System.Delegate**, e.g. `System.Func<int,int>`).
+/// # Example
+/// ## C#
+///```csharp
+/// class SomeClass{
+///     [MethodImplAttribute(MethodImplOptions.InternalCall)]
+///     public static extern int CallCallback(System.Func<int,int> callback);
+/// }
+///```
+/// ## Rust
+///```no_run
+/// # use wrapped_mono::*;
+/// #[invokable]
+/// fn call_callback(callback:Delegate)->i32{
+///     callback.invoke(&[&21])
+///         .expect("Callback threw an exception!")
+///         .expect("Callback returned null!")
+///         .unbox::<i32>()
+/// }
+/// ```
+pub struct Delegate {
     #[cfg(not(feature = "referenced_objects"))]
-    dptr: *mut MonoDelegate,
+    del_ptr: *mut crate::binds::MonoDelegate,
     #[cfg(feature = "referenced_objects")]
     handle: GCHandle,
-    args_type: PhantomData<Args>,
 }
-impl<Args: InteropSend> Delegate<Args> {
-    fn get_ptr(&self) -> *mut MonoDelegate {
-        #[cfg(not(feature = "referenced_objects"))]
-        {
-            self.dptr
-        }
-        #[cfg(feature = "referenced_objects")]
-        {
-            self.handle.get_target() as *mut MonoDelegate
-        }
-    }
-    /// Counts number of parameters(arguments) this function accepts.
-    /// # Arguments
-    /// |Name   |Type   |Description|
-    /// |-------|-------|------|
-    /// |self|&[Delegate]|Rust representation of a delegate to get argument count of|
-    pub fn get_param_count(&self) -> u32 {
-        #[cfg(feature = "referenced_objects")]
-        let marker = gc_unsafe_enter();
-        let sig = unsafe { crate::binds::mono_method_signature(self.get_method_ptr()) };
-        let pcount = unsafe { crate::binds::mono_signature_get_param_count(sig) };
-        #[cfg(feature = "referenced_objects")]
-        gc_unsafe_exit(marker);
-        pcount
+impl Delegate {
+    // Pointer to the `Invoke` method of this delegate.
+    fn get_invoke_ptr(&self) -> *mut MonoMethod {
+        unsafe { crate::binds::mono_get_delegate_invoke(self.get_class().get_ptr()) }
     }
-    /// Returns list of classes of parameters of delegate *self*.
-    /// # Arguments
-    /// |Name   |Type   |Description|
-    /// |-------|-------|------|
-    /// |self|&[`Delegate`]|Rust representation of a delegate to get argument types off|
+    /// Returns classes of parameters this delegate accepts.
+    #[must_use]
     pub fn get_params(&self) -> Vec<Class> {
-        #[cfg(feature = "referenced_objects")]
-        let marker = gc_unsafe_enter();
-        let sig = unsafe { crate::binds::mono_method_signature(self.get_method_ptr()) };
+        let sig = unsafe { crate::binds::mono_method_signature(self.get_invoke_ptr()) };
         let mut iter: usize = 0;
-        let mut res = Vec::with_capacity(self.get_param_count() as usize);
+        let mut res = Vec::new();
         while let Some(class) = unsafe {
-            Class::from_ptr({
-                let ptr = crate::binds::mono_signature_get_params(
-                    sig,
-                    &mut iter as *mut usize as *mut *mut c_void,
-                );
-                if ptr.is_null() {
-                    null_mut()
-                } else {
-                    crate::binds::mono_class_from_mono_type(ptr)
-                }
-            })
+            let ptr = crate::binds::mono_signature_get_params(
+                sig,
+                std::ptr::addr_of_mut!(iter).cast::<*mut c_void>(),
+            );
+            if ptr.is_null() {
+                None
+            } else {
+                Class::from_ptr(crate::binds::mono_class_from_mono_type(ptr))
+            }
         } {
             res.push(class);
         }
-        #[cfg(feature = "referenced_objects")]
-        gc_unsafe_exit(marker);
         res
     }
-    // Gets names of all parameters delegate *self* accepts.
-    /// # Arguments
-    /// |Name   |Type   |Description|
-    /// |-------|-------|------|
-    /// |self|&[`Delegate`]|Rust representation of a delegate to get names of arguments off|
-    pub fn get_param_names(&self) -> Vec<String> {
-        use std::ffi::CString;
-        let pcount = self.get_param_count() as usize;
-        let mut ptrs: Vec<*const i8> = Vec::with_capacity(pcount);
-        ptrs.resize(pcount, std::ptr::null::<i8>());
+    /// Invokes this delegate with *args*. Returns value returned by the delegate, boxed if it is a value type.
+    /// # Errors
+    /// Returns an exception if it was thrown by the delegate, or `System.MissingMethodException` if the delegate can't be invoked with *args*.
+    pub fn invoke(&self, args: &[&dyn InvokeArg]) -> Result<Option<Object>, Exception> {
+        if !args_match(&self.get_params(), args) {
+            return Err(Exception::missing_method(
+                &self.get_class().get_name(),
+                "Invoke",
+            ));
+        }
+        let (mut params, roots) = invoke_ptrs(args);
+        let mut except: *mut MonoObject = null_mut();
         #[cfg(feature = "referenced_objects")]
         let marker = gc_unsafe_enter();
-        unsafe {
-            crate::binds::mono_method_get_param_names(
-                self.get_method_ptr(),
-                ptrs.as_ptr() as *mut *const i8,
-            )
-        };
-        let mut res: Vec<String> = Vec::with_capacity(pcount);
-        for ptr in &ptrs {
-            let cstr = unsafe { CString::from_raw(*ptr as *mut i8) };
-            res.push(
-                cstr.to_str()
-                    .expect("Could not create String from ptr")
-                    .to_owned(),
+        let res = unsafe {
+            let res_ptr = crate::binds::mono_runtime_invoke(
+                self.get_invoke_ptr(),
+                self.get_ptr().cast(),
+                params.as_mut_ptr(),
+                std::ptr::addr_of_mut!(except),
             );
-            let _ = cstr.into_raw();
-        }
-        drop(ptrs);
+            match Exception::from_ptr(except) {
+                Some(except) => Err(except),
+                None => Ok(Object::from_ptr(res_ptr)),
+            }
+        };
         #[cfg(feature = "referenced_objects")]
         gc_unsafe_exit(marker);
+        drop(roots);
         res
     }
-    /// Returns the return type of delegate *self*, if no return type returns *System.Void*
-    /// # Arguments
-    /// |Name   |Type   |Description|
-    /// |-------|-------|------|
-    /// |self|&[`Delegate`]|Rust representation of a delegate to get return type off|
-    pub fn get_return(&self) -> Class {
-        let sig = unsafe { crate::binds::mono_method_signature(self.get_method_ptr()) };
-        let ptr = unsafe { crate::binds::mono_signature_get_return_type(sig) };
-        unsafe {
-            Class::from_ptr(crate::binds::mono_class_from_mono_type(ptr)).expect("Got no method return type, but no return type should be signaled by System.Void type!")
-        }
-    }
-    fn get_method_ptr(&self) -> *mut MonoMethod {
-        unsafe { crate::binds::mono_get_delegate_invoke(self.get_class().get_ptr()) }
+    /// Returns the object this delegate invokes its method on, or [`None`] if the method is static.
+    #[must_use]
+    pub fn get_target(&self) -> Option<Object> {
+        self.to_object()
+            .call("get_Target", ())
+            .expect("Got an exception while getting target of a delegate!")
+    }
+    /// Returns the method this delegate invokes. Returns [`None`] if the method does not accept arguments of type *Args*.
+    #[must_use]
+    pub fn get_method<Args: TupleToFFIPtrs + CompareClasses>(&self) -> Option<Method<Args>> {
+        let method_info = self
+            .to_object()
+            .call("get_Method", ())
+            .expect("Got an exception while getting method of a delegate!")?;
+        crate::method::method_from_method_info(&method_info)
+    }
+    fn to_object(&self) -> Object {
+        unsafe { Object::from_ptr(self.get_ptr()) }
+            .expect("Could not convert a non-null delegate to Object!")
     }
 }
-/// Trait implemented only for [`Delegate`] type. Splits some functions up from main [`Method`] type, allowing for different amount of delegate arguments.
-pub trait DelegateTrait<Args: InteropSend> {
-    /// Creates new Delegate type from a *mut MonoDelegate. Checks if arguments of [`MonoDelegate`] and rust representation of a [`Delegate`] match and if not panic.
-    /// Returns [`None`] if pointer is null.
-    /// # Arguments
-    /// |Name   |Type   |Description|
-    /// |-------|-------|------|
-    /// |met_ptr|*mut [`MonoDelegate`]|Pointer to delegate to create a representation for.|
-    /// # Safety
-    /// Pointer must be either a valid pointer to [`MonoDelegate`] received from mono runtime, or a null pointer.
-    /// **WARNING** argument types not yet checked for delegates with 1 or 0 arguments.
-    /// This results from limitations of Rust type system and this version of the API,
-    /// and can't be solved without some really nasty hacks,
-    /// but will be fixed in the future.
-    unsafe fn from_ptr(ptr: *mut MonoDelegate) -> Option<Self>
-    where
-        Self: Sized;
-    /// Creates new Delegate type from a *mut MonoDelegate. Checks if arguments of [`MonoDelegate`] and rust representation of a [`Delegate`] match and if not returns None.
-    /// Returns [`None`] if pointer is null.
-    /// # Arguments
-    /// |Name   |Type   |Description|
-    /// |-------|-------|------|
-    /// |met_ptr|*mut [`MonoDelegate`]|Pointer to delegate to create a representation for.|
-    /// # Safety
-    /// Pointer must be either a valid pointer to [`MonoDelegate`] received from mono runtime, or a null pointer.
-    /// **WARNING** argument types not yet checked for delegates with 1 or 0 arguments.
-    /// This results from limitations of Rust type system and this version of the API,
-    /// and can't be solved without some really nasty hacks,
-    /// but will be fixed in the future.
-    unsafe fn from_ptr_checked(ptr: *mut MonoDelegate) -> Option<Self>
-    where
-        Self: Sized;
-    /// Invokes this delegate.
-    /// # Arguments
-    /// | Name   | Type   | Description|
-    /// |--------|--------|-------|
-    /// | self   | &`Self`|Reference to delegate to invoke. |
-    /// | args   | `Args`|Arguments to pass to delegate |
-    fn invoke(&self, params: Args) -> Result<Option<Object>, Exception>;
-}
-impl<Args: InteropSend> DelegateTrait<Args> for Delegate<Args> {
-    default unsafe fn from_ptr(ptr: *mut MonoDelegate) -> Option<Self> {
-        if ptr.is_null() {
-            None
-        } else {
-            #[cfg(not(feature = "referenced_objects"))]
-            {
-                Some(Self {
-                    dptr: ptr,
-                    args_type: PhantomData,
-                })
-            }
-            #[cfg(feature = "referenced_objects")]
-            {
-                Some(Self {
-                    handle: GCHandle::create_default(ptr as *mut crate::binds::MonoObject),
-                    args_type: PhantomData,
-                })
-            }
-        }
-    }
-    default unsafe fn from_ptr_checked(ptr: *mut MonoDelegate) -> Option<Self> {
-        if ptr.is_null() {
-            None
-        } else {
-            #[cfg(not(feature = "referenced_objects"))]
-            {
-                Some(Self {
-                    dptr: ptr,
-                    args_type: PhantomData,
-                })
-            }
-            #[cfg(feature = "referenced_objects")]
-            {
-                Some(Self {
-                    handle: GCHandle::create_default(ptr as *mut crate::binds::MonoObject),
-                    args_type: PhantomData,
-                })
-            }
+impl ObjectTrait for Delegate {
+    fn get_ptr(&self) -> *mut MonoObject {
+        #[cfg(not(feature = "referenced_objects"))]
+        {
+            self.del_ptr.cast()
         }
-    }
-    default fn invoke(&self, params: Args) -> Result<Option<Object>, Exception> {
-        let mut expect: *mut MonoException = null_mut();
-        //convert argument types
-        let mut args = <Args as InteropSend>::get_mono_rep(params);
-        //convert arguments to pointers
-        let mut params = &mut args as *mut _ as *mut c_void;
         #[cfg(feature = "referenced_objects")]
-        let marker = gc_unsafe_enter();
-        //invoke the delegate itself
-        let res_ptr = unsafe {
-            crate::binds::mono_runtime_delegate_invoke(
-                self.get_ptr() as *mut _,
-                &mut params as *mut *mut c_void,
-                &mut expect as *mut *mut MonoException as *mut *mut MonoObject,
-            )
-        };
-        //hold args as long as params lives.
-        crate::hold(&args);
-        //get result
-        let res = unsafe { Object::from_ptr(res_ptr) };
-        println!("expect:{}", expect as usize);
-        if expect.is_null() {
-            #[cfg(feature = "referenced_objects")]
-            gc_unsafe_exit(marker);
-            Ok(res)
-        } else {
-            let except = unsafe {
-                Exception::from_ptr(expect)
-                    .expect("Impossible: pointer is null and not null at the same time.")
-            };
-            #[cfg(feature = "referenced_objects")]
-            gc_unsafe_exit(marker);
-            Err(except)
-        }
-    }
-}
-impl<Args: InteropSend> DelegateTrait<Args> for Delegate<Args>
-where
-    <Args as InteropSend>::TargetType: TupleToPtrs + CompareClasses,
-{
-    default unsafe fn from_ptr(ptr: *mut MonoDelegate) -> Option<Self> {
-        let res = {
-            if ptr.is_null() {
-                return None;
-            } else {
-                #[cfg(not(feature = "referenced_objects"))]
-                {
-                    Self {
-                        dptr: ptr,
-                        args_type: PhantomData,
-                    }
-                }
-                #[cfg(feature = "referenced_objects")]
-                {
-                    Self {
-                        handle: GCHandle::create_default(ptr as *mut crate::binds::MonoObject),
-                        args_type: PhantomData,
-                    }
-                }
-            }
-        };
-        // Do type checks
-        let params = res.get_params();
-        if !<<Args as InteropSend>::TargetType as CompareClasses>::compare(&params) {
-            use std::fmt::Write;
-            let mut msg = format!(
-                "Delegate Type Mismatch! Got a delegate accepting {} arguments of types:",
-                params.len()
-            );
-            for param in params {
-                write!(msg, ",\"{}\"", param.get_name_sig())
-                    .expect("Could not print improper function argument types!");
-            }
-            panic!("{}", msg);
+        {
+            self.handle.get_target()
         }
-        Some(res)
     }
-    default unsafe fn from_ptr_checked(ptr: *mut MonoDelegate) -> Option<Self> {
-        let res = {
-            if ptr.is_null() {
-                return None;
-            } else {
-                #[cfg(not(feature = "referenced_objects"))]
-                {
-                    Self {
-                        dptr: ptr,
-                        args_type: PhantomData,
-                    }
-                }
-                #[cfg(feature = "referenced_objects")]
-                {
-                    Self {
-                        handle: GCHandle::create_default(ptr as *mut crate::binds::MonoObject),
-                        args_type: PhantomData,
-                    }
-                }
+    unsafe fn from_ptr_unchecked(ptr: *mut MonoObject) -> Self {
+        #[cfg(not(feature = "referenced_objects"))]
+        {
+            Self {
+                del_ptr: ptr.cast(),
             }
-        };
-        // Do type checks
-        let params = res.get_params();
-        if !<<Args as InteropSend>::TargetType as CompareClasses>::compare(&params) {
-            None
-        } else {
-            Some(res)
         }
-    }
-    default fn invoke(&self, params: Args) -> Result<Option<Object>, Exception> {
-        let mut expect: *mut MonoException = null_mut();
-        //convert argument types
-        let mut args = <Args as InteropSend>::get_mono_rep(params);
-        let mut params =
-            <<Args as InteropSend>::TargetType as TupleToPtrs>::get_ptrs(&mut args as *mut _);
         #[cfg(feature = "referenced_objects")]
-        let marker = gc_unsafe_enter();
-        //invoke the delegate itself
-        let res_ptr = unsafe {
-            crate::binds::mono_runtime_delegate_invoke(
-                self.get_ptr() as *mut _,
-                &mut params as *mut _ as *mut *mut c_void,
-                &mut expect as *mut *mut MonoException as *mut *mut MonoObject,
-            )
-        };
-        //hold args as long as params lives.
-        crate::hold(&args);
-        //get result
-        let res = unsafe { Object::from_ptr(res_ptr) };
-        if expect.is_null() {
-            #[cfg(feature = "referenced_objects")]
-            gc_unsafe_exit(marker);
-            Ok(res)
-        } else {
-            let except = unsafe {
-                Exception::from_ptr(expect)
-                    .expect("Impossible: pointer is null and not null at the same time.")
-            };
-            #[cfg(feature = "referenced_objects")]
-            gc_unsafe_exit(marker);
-            Err(except)
+        {
+            Self {
+                handle: GCHandle::create_default(ptr),
+            }
         }
     }
 }
-
-impl<Args: InteropSend> InteropReceive for Delegate<Args> {
-    type SourceType = *mut MonoDelegate;
-    // unless this function is abused, this argument should come from the mono runtime, so it should be always valid.
-    #[allow(clippy::not_unsafe_ptr_arg_deref)]
-    fn get_rust_rep(ptr: *mut MonoDelegate) -> Delegate<Args> {
-        unsafe { Self::from_ptr(ptr).expect("Expected non-null value but got null") }
-    }
-}
-impl<Args: InteropSend> InteropReceive for Option<Delegate<Args>> {
-    type SourceType = *mut MonoDelegate;
-    // unless this function is abused, this argument should come from the mono runtime, so it should be always valid.
-    #[allow(clippy::not_unsafe_ptr_arg_deref)]
-    fn get_rust_rep(ptr: *mut MonoDelegate) -> Option<Delegate<Args>> {
-        unsafe { Delegate::from_ptr(ptr) }
-    }
-}
-impl<Args: InteropSend> InteropClass for Delegate<Args> {
+impl InteropClass for Delegate {
     fn get_mono_class() -> Class {
         Class::get_delegate_class()
     }
 }
-impl<Args: InteropSend> InteropSend for Delegate<Args> {
-    type TargetType = *mut MonoDelegate;
-    fn get_mono_rep(input: Delegate<Args>) -> Self::TargetType {
-        input.get_ptr()
-    }
-}
-impl<Args: InteropSend> ObjectTrait for Delegate<Args> {
-    fn hash(&self) -> i32 {
-        unsafe { crate::binds::mono_object_hash(self.get_ptr() as *mut _) }
-    }
-    fn get_domain(&self) -> Domain {
-        unsafe {
-            Domain::from_ptr(crate::binds::mono_object_get_domain(
-                self.get_ptr() as *mut _
-            ))
-        }
-    }
-    fn get_size(&self) -> u32 {
-        unsafe { crate::binds::mono_object_get_size(self.get_ptr() as *mut _) }
-    }
-    fn reflection_get_token(&self) -> u32 {
-        unsafe { crate::binds::mono_reflection_get_token(self.get_ptr() as *mut _) }
-    }
-    fn get_class(&self) -> Class {
-        unsafe {
-            Class::from_ptr(crate::binds::mono_object_get_class(self.get_ptr() as *mut _))
-                .expect("Could not get class of an object")
-        }
-    }
-    fn to_mstring(&self) -> Result<Option<MString>, Exception> {
-        let mut exc: *mut crate::binds::MonoException = core::ptr::null_mut();
-        let res = unsafe {
-            MString::from_ptr(crate::binds::mono_object_to_string(
-                self.get_ptr() as *mut _,
-                &mut exc as *mut *mut crate::binds::MonoException
-                    as *mut *mut crate::binds::MonoObject,
-            ))
-        };
-        let exc = unsafe { Exception::from_ptr(exc) };
-        match exc {
-            Some(e) => Err(e),
-            None => Ok(res),
-        }
-    }
-    fn cast_to_object(&self) -> Object {
-        unsafe { Object::from_ptr(self.get_ptr() as *mut _) }.unwrap() /*Failure impossible, object is always an object.*/
-    }
-    fn cast_from_object(obj: &Object) -> Option<Self> {
-        if !Self::get_mono_class().is_assignable_from(&obj.get_class()) {
-            None
-        } else {
-            unsafe { Self::from_ptr_checked(obj.get_ptr() as *mut _) }
-        }
+impl Clone for Delegate {
+    fn clone(&self) -> Self {
+        unsafe { Self::from_ptr_unchecked(self.get_ptr()) }
     }
 }
-impl<O: ObjectTrait, Args: InteropSend> PartialEq<O> for Delegate<Args> {
+impl<O: ObjectTrait> PartialEq<O> for Delegate {
     fn eq(&self, other: &O) -> bool {
-        self.get_ptr() as *mut _ == other.cast_to_object().get_ptr()
+        self.get_ptr() == other.get_ptr()
     }
 }
@@ -1,13 +1,16 @@
-use crate::assembly::Assembly;
+use crate::assembly::{Assembly, AssemblyLoadError};
 use crate::binds::{mono_domain_assembly_open, mono_domain_create, MonoDomain};
 /// Safe representation of [`MonoDomain`] type.
 #[derive(Eq, Clone, Copy)]
 pub struct Domain {
     ptr: *mut MonoDomain,
 }
+use crate::tupleutilis::{CompareClasses, TupleToFFIPtrs};
+use crate::{Class, Exception, Method, Object, ObjectTrait};
 use std::ffi::CString;
 impl Domain {
     /// Loads [`Assembly`] at path into domain, returns **None** if assembly could not be loaded(is missing or broken), and `Some(Assembly)` if it was successfully loaded.
+    /// To find out why loading failed, use [`Self::try_assembly_open`].
     #[must_use]
     pub fn assembly_open(&self, path: &str) -> Option<Assembly> {
         //! # Example
@@ -24,6 +27,29 @@ impl Domain {
         let _ = &cstr;
         Some(unsafe { Assembly::from_ptr(ptr) })
     }
+    /// Loads [`Assembly`] at *path* into domain. Unlike [`Self::assembly_open`], reports why the assembly could not be loaded.
+    /// # Example
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// # let domain = jit::init("name",None);
+    /// match domain.try_assembly_open("SomeAssembly.dll"){
+    ///     Ok(asm)=>println!("Loaded {}",asm.get_name()),
+    ///     Err(AssemblyLoadError::ErrorErrno)=>eprintln!("SomeAssembly.dll is missing or can't be read"),
+    ///     Err(err)=>eprintln!("Could not load SomeAssembly.dll:{err}"),
+    /// }
+    /// ```
+    /// # Errors
+    /// Returns [`AssemblyLoadError`] matching the status reported by the runtime, same as [`Assembly::open`].
+    pub fn try_assembly_open(&self, path: &str) -> Result<Assembly, AssemblyLoadError> {
+        // `Assembly::open` loads into the current domain, so this domain must be current while it loads.
+        let prev = unsafe { crate::binds::mono_domain_get() };
+        unsafe { crate::binds::mono_domain_set(self.ptr, 0) };
+        let res = Assembly::open(path);
+        if !prev.is_null() {
+            unsafe { crate::binds::mono_domain_set(prev, 0) };
+        }
+        res
+    }
     /// Creates a new empty domain
     /// # Example
     /// ```no_run
@@ -68,14 +94,42 @@ impl Domain {
     pub fn attach_thread(&self) {
         unsafe { crate::binds::mono_jit_thread_attach(self.ptr) };
     }
-    /* TODO: fix domain unloading/freeing
-    /// [DOES not work]
-    fn unload(self){
-        self.set(true);
-        self.attach();
-        unsafe{crate::binds::mono_domain_unload(self.ptr)};
-        drop(self);
+    /// Unloads this domain, together with all assemblies loaded into it. Finalizers of objects inside the domain are run, and threads executing in it are stopped.
+    /// If this domain is the current one, the root domain becomes current.
+    ///
+    /// The root domain(returned by [`crate::jit::init`]) can't be unloaded this way, use [`crate::jit::cleanup`] to shut down the runtime instead.
+    /// All references to objects inside the unloaded domain become invalid.
+    /// # Example
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// let root = jit::init("root",None);
+    /// let plugin_domain = Domain::create();
+    /// let plugin = plugin_domain.assembly_open("SomePlugin.dll").expect("Could not load plugin!");
+    /// // Use the plugin...
+    /// plugin_domain.unload().expect("Could not unload plugin domain!");
+    /// ```
+    /// # Errors
+    /// Returns `System.CannotUnloadAppDomainException` if this is the root domain, or an exception thrown by the runtime while unloading.
+    pub fn unload(self) -> Result<(), Exception> {
+        let root = unsafe { crate::binds::mono_get_root_domain() };
+        if self.ptr == root {
+            return Err(Exception::cannot_unload_appdomain(
+                "The root domain can't be unloaded.",
+            ));
+        }
+        // A domain can't be unloaded while it is the current one.
+        if unsafe { crate::binds::mono_domain_get() } == self.ptr {
+            unsafe { crate::binds::mono_domain_set(root, 0) };
+        }
+        let mut exc: *mut crate::binds::MonoObject = std::ptr::null_mut();
+        unsafe { crate::binds::mono_domain_try_unload(self.ptr, std::ptr::addr_of_mut!(exc)) };
+        crate::class::invalidate_class_caches();
+        match unsafe { Exception::from_ptr(exc) } {
+            Some(exc) => Err(exc),
+            None => Ok(()),
+        }
     }
+    /* TODO: fix domain freeing
     /// Releases resources related to a specific domain. If *force* is true, allows releasing of the root domain. Used during shut-down.
     /// # Safety
     /// Since this function releases all resources related to given domain, it means that all references to objects inside it will become invalid.
@@ -84,6 +138,35 @@ impl Domain {
         drop(self);
     }
     */
+    /// Creates an instance of type *`full_type_name`*(e.g. `SomeNamespace.SomeClass`) from *assembly* in this domain, calling its constructor accepting *args*.
+    /// Equivalent of `Activator.CreateInstance` in managed code.
+    /// # Example
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// # let domain = jit::init("name",None);
+    /// let asm = domain.assembly_open("SomePlugin.dll").expect("Could not load assembly!");
+    /// let plugin = domain.create_instance(&asm,"SomePlugin.Plugin",&(42,)).expect("Could not create plugin!");
+    /// ```
+    /// # Errors
+    /// Returns [`CreateError`] if type could not be found, it has no constructor accepting *args* or the constructor threw an exception.
+    pub fn create_instance<Args: TupleToFFIPtrs + CompareClasses + Clone>(
+        &self,
+        assembly: &Assembly,
+        full_type_name: &str,
+        args: &Args,
+    ) -> Result<Object, CreateError> {
+        let (namespace, name) = full_type_name
+            .rsplit_once('.')
+            .unwrap_or(("", full_type_name));
+        let class = Class::from_name(&assembly.get_image(), namespace, name)
+            .ok_or_else(|| CreateError::TypeNotFound(full_type_name.to_owned()))?;
+        let ctor = Method::<Args>::find_overload(&class, ".ctor")
+            .ok_or_else(|| CreateError::ConstructorNotFound(full_type_name.to_owned()))?;
+        let obj = Object::new(self, &class);
+        ctor.invoke(Some(obj.clone()), args.clone())
+            .map_err(CreateError::Exception)?;
+        Ok(obj)
+    }
     /// Returns current domain or `None` if mono runtime is not initialized yet.
     #[must_use]
     pub fn get_current() -> Option<Self> {
@@ -103,3 +186,28 @@ impl std::cmp::PartialEq for Domain {
 }
 // Domains are OK to share between threads
 unsafe impl Sync for Domain {}
+/// Error returned by [`Domain::create_instance`].
+#[derive(Debug)]
+pub enum CreateError {
+    /// Type with this name does not exist in the assembly.
+    TypeNotFound(String),
+    /// Type has no constructor accepting the given arguments.
+    ConstructorNotFound(String),
+    /// Constructor threw an exception.
+    Exception(Exception),
+}
+impl std::fmt::Display for CreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TypeNotFound(name) => write!(f, "could not find type `{name}`"),
+            Self::ConstructorNotFound(name) => {
+                write!(
+                    f,
+                    "type `{name}` has no constructor accepting given arguments"
+                )
+            }
+            Self::Exception(exception) => write!(f, "constructor threw an exception:{exception}"),
+        }
+    }
+}
+impl std::error::Error for CreateError {}
@@ -1,6 +1,6 @@
 use crate::binds::MonoObject;
 use crate::gc::{gc_unsafe_enter, gc_unsafe_exit, GCHandle};
-use crate::{Class, Domain, Image, InteropClass, ObjectTrait};
+use crate::{Class, Domain, Image, InteropClass, MString, Method, Object, ObjectTrait};
 use std::ffi::CString;
 /// Safe representation of `MonoException`.
 pub struct Exception {
@@ -602,6 +602,53 @@ impl Exception {
         gc_unsafe_exit(marker);
         res
     }
+    /// Returns value of the managed `Message` property of this exception.
+    /// # Panics
+    /// Panics if getter of `Message` threw an exception.
+    #[must_use]
+    pub fn message(&self) -> String {
+        let get_message: Method<()> =
+            Method::get_from_name(&Class::get_exception_class(), "get_Message", 0)
+                .expect("Could not find Exception.Message getter!");
+        let obj = unsafe { Object::from_ptr(self.get_ptr()) }
+            .expect("Could not convert a non-null exception to Object!");
+        let get_message = Object::get_virtual_method(&obj, &get_message)
+            .expect("Could not find an override of Exception.Message getter!");
+        get_message
+            .invoke(Some(obj), ())
+            .expect("Got an exception while reading Exception.Message!")
+            .and_then(|message| message.cast::<MString>())
+            .map(|message| message.to_string())
+            .unwrap_or_default()
+    }
+    /// Returns value of the managed `StackTrace` property of this exception, or [`None`] if it was never thrown.
+    /// # Panics
+    /// Panics if getter of `StackTrace` threw an exception.
+    #[must_use]
+    pub fn stack_trace(&self) -> Option<String> {
+        let obj = unsafe { Object::from_ptr(self.get_ptr()) }
+            .expect("Could not convert a non-null exception to Object!");
+        obj.call("get_StackTrace", ())
+            .expect("Got an exception while reading Exception.StackTrace!")
+            .and_then(|trace| trace.cast::<MString>())
+            .map(|trace| trace.to_string())
+    }
+    /// Returns value of the managed `InnerException` property of this exception(the exception which caused this one), or [`None`] if there is none.
+    /// # Panics
+    /// Panics if getter of `InnerException` threw an exception.
+    #[must_use]
+    pub fn inner(&self) -> Option<Self> {
+        let obj = unsafe { Object::from_ptr(self.get_ptr()) }
+            .expect("Could not convert a non-null exception to Object!");
+        obj.call("get_InnerException", ())
+            .expect("Got an exception while reading Exception.InnerException!")
+            .and_then(|inner| inner.cast::<Self>())
+    }
+    /// Returns full name of class of this exception(e.g. `System.DivideByZeroException`).
+    #[must_use]
+    pub fn class_name(&self) -> String {
+        self.get_class().get_name_sig()
+    }
     /// Creates [`Exception`] with a wrapped inner [`Exception`] *inner*.
     #[must_use]
     pub fn wrapped(inner: &Self) -> Self {
@@ -714,3 +761,4 @@ impl std::fmt::Display for Exception {
         )
     }
 }
+impl std::error::Error for Exception {}
@@ -34,12 +34,38 @@ pub fn get_heap_size() -> i64 {
 pub fn get_used_size() -> i64 {
     unsafe { crate::binds::mono_gc_get_used_size() }
 }
+/// Gets the name of the garbage collector used by the runtime: `"sgen"` or `"boehm"`.
+/// Boehm is not generational, so it is told apart from SGen by its max generation.
+#[must_use]
+pub fn get_gc_name() -> String {
+    if max_generation() == 0 {
+        "boehm".to_owned()
+    } else {
+        "sgen".to_owned()
+    }
+}
+/// Checks if the garbage collector marks objects concurrently, instead of stopping the world for the whole collection.
+/// Only SGen with a concurrent major collector(`marksweep-conc`, the default) works this way.
+/// The major collector may be changed by setting `major=` inside the `MONO_GC_PARAMS` environment variable.
+#[must_use]
+pub fn is_concurrent() -> bool {
+    if get_gc_name() != "sgen" {
+        return false;
+    }
+    let params = std::env::var("MONO_GC_PARAMS").unwrap_or_default();
+    params
+        .split(',')
+        .rev()
+        .find_map(|param| param.trim().strip_prefix("major="))
+        .is_none_or(|major| major.starts_with("marksweep-conc"))
+}
 /// A Garbage Collector handle. Should only be used if default feature referenced objects is disabled.
 /// Otherwise, all of its functionality is handled automatically behind the scenes
 pub struct GCHandle {
     handle: u32,
 }
 use crate::binds::MonoObject;
+use std::ffi::c_void;
 impl GCHandle {
     /// Gets a pointer to an object this handle targets.
     #[must_use]
@@ -60,6 +86,42 @@ impl GCHandle {
     pub unsafe fn create_default(ptr: *mut MonoObject) -> Self {
         Self::create(ptr, false)
     }
+    /// Creates a new pinned handle to *obj*. While the handle exists, the object will not be moved by the garbage collector,
+    /// so address of its data returned by [`Self::addr`] stays valid and can be passed to native code.
+    ///
+    /// The handle must outlive any native use of the address. Without the `referenced_objects` feature, the handle is not freed automatically,
+    /// and the object stays pinned until the handle is freed with [`Self::free`].
+    /// # Example
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// # let buffer:Object = todo!();
+    /// let pinned = gc::GCHandle::new_pinned(&buffer);
+    /// let data = pinned.addr().cast::<u8>();
+    /// // pass data to native code...
+    /// gc::GCHandle::free(pinned);
+    /// ```
+    #[must_use]
+    pub fn new_pinned(obj: &Object) -> Self {
+        unsafe { Self::create(obj.get_ptr(), true) }
+    }
+    /// Returns address of data of object this handle targets: address of the first element for arrays, and address of the first field for other objects.
+    /// The address is stable only if the handle is pinned(created with [`Self::new_pinned`]).
+    #[must_use]
+    pub fn addr(&self) -> *mut c_void {
+        let obj = self.get_target();
+        let is_array =
+            unsafe { crate::binds::mono_class_get_rank(crate::binds::mono_object_get_class(obj)) }
+                > 0;
+        if is_array {
+            unsafe { crate::binds::mono_array_addr_with_size(obj.cast(), 0, 0).cast() }
+        } else {
+            unsafe {
+                obj.cast::<u8>()
+                    .add(std::mem::size_of::<MonoObject>())
+                    .cast()
+            }
+        }
+    }
     /// Frees this handle, deleting the reference to object it targets.
     #[allow(clippy::needless_pass_by_value)] //Not needles. Self is really consumed here.
     pub fn free(handle: Self) {
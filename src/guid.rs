@@ -0,0 +1,58 @@
+use crate::interop::{InteropBox, InteropClass, InteropReceive, InteropSend};
+use crate::Class;
+use std::sync::LazyLock;
+/// Representation of the managed `System.Guid` struct. Passed to and from managed code by value, and can be boxed and unboxed.
+/// # Example
+/// ## C#
+/// ```csharp
+/// class SomeClass{
+///     [MethodImplAttribute(MethodImplOptions.InternalCall)]
+///     public static extern void StoreId(System.Guid id);
+/// }
+/// ```
+/// ## Rust
+/// ```no_run
+/// # use wrapped_mono::*;
+/// #[invokable]
+/// fn store_id(id:Guid){
+///     println!("Got id {:?}",id.to_bytes());
+/// }
+/// ```
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Guid {
+    bytes: [u8; 16],
+}
+impl Guid {
+    /// Creates [`Guid`] from *bytes* laid out like the result of managed `Guid.ToByteArray`.
+    #[must_use]
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self { bytes }
+    }
+    /// Returns bytes of this [`Guid`], laid out like the result of managed `Guid.ToByteArray`.
+    #[must_use]
+    pub const fn to_bytes(&self) -> [u8; 16] {
+        self.bytes
+    }
+}
+impl InteropReceive for Guid {
+    type SourceType = Self;
+    fn get_rust_rep(mono_arg: Self::SourceType) -> Self {
+        mono_arg
+    }
+}
+// `System.Guid` is a struct with the same size as `Guid`, and its fields are stored in the same order as in `Guid.ToByteArray`.
+unsafe impl InteropSend for Guid {}
+impl InteropClass for Guid {
+    fn get_mono_class() -> Class {
+        *GUID_CLASS
+    }
+}
+impl InteropBox for Guid {}
+static GUID_CLASS: LazyLock<Class> = LazyLock::new(|| {
+    let img = crate::Assembly::assembly_loaded("mscorlib")
+        .expect("Assembly mscorlib not loaded, could not get System.Guid class!")
+        .get_image();
+    Class::from_name_case(&img, "System", "Guid")
+        .expect("Could not get System.Guid class form mscorlib!")
+});
@@ -7,6 +7,8 @@ pub struct Image {
 #[allow(unused_imports)]
 use crate::binds::MonoAssembly; // For documentation
 use crate::metadata::{MetadataTableInfo, MetadataTableKind, MetadataToken};
+use crate::tupleutilis::{CompareClasses, TupleToFFIPtrs};
+use crate::Method;
 use std::ffi::CString;
 impl Image {
     /// Gets metadata table from an image.
@@ -57,6 +59,45 @@ impl Image {
     pub fn init(&self) {
         unsafe { crate::binds::mono_image_init(self.img_ptr) };
     }
+    /// Returns entry point(`Main` method) of this image, or [`None`] if it has no entry point(e.g. it is a library) or *Args* do not match its parameters.
+    /// # Example
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// # let img:Image = todo!();
+    /// let main:Method<(Array<Dim1D,String>,)> = img.get_entry_point().expect("Image has no entry point!");
+    /// ```
+    #[must_use]
+    pub fn get_entry_point<Args: TupleToFFIPtrs + CompareClasses>(&self) -> Option<Method<Args>> {
+        let token = unsafe { crate::binds::mono_image_get_entry_point(self.img_ptr) };
+        if token == 0 {
+            return None;
+        }
+        unsafe {
+            Method::from_ptr(crate::binds::mono_get_method(
+                self.img_ptr,
+                token,
+                std::ptr::null_mut(),
+            ))
+        }
+    }
+    /// Returns method with metadata *token*(e.g. a `MethodDef` token returned by [`Method::get_token`]), or [`None`] if the token is invalid or *Args* do not match its parameters.
+    #[must_use]
+    pub fn get_method<Args: TupleToFFIPtrs + CompareClasses>(
+        &self,
+        token: u32,
+    ) -> Option<Method<Args>> {
+        // Rows are indexed from 1, and the runtime does not check for row 0.
+        if token & 0x00FF_FFFF == 0 {
+            return None;
+        }
+        unsafe {
+            Method::from_ptr(crate::binds::mono_get_method(
+                self.img_ptr,
+                token,
+                std::ptr::null_mut(),
+            ))
+        }
+    }
     /// Returns name of this image
     #[must_use]
     pub fn get_name(&self) -> String {
@@ -71,6 +112,7 @@ impl Image {
     /// All references to types within image will be made invalid after this call.
     pub unsafe fn close(&mut self) {
         crate::binds::mono_image_close(self.img_ptr);
+        crate::class::invalidate_class_caches();
         let _ = &self;
     }
 }
@@ -3,6 +3,30 @@ use std::os::raw::c_void;
 use crate::object::ObjectTrait;
 /// Trait specifying how to convert a type when transferring it between managed and unmanaged code. It specifies how to convert
 /// `SourceType` used by `MonoRuntime` to type implementing this trait.
+/// # Example
+/// Custom type passed as `int` to and from managed code:
+/// ```no_run
+/// # use wrapped_mono::*;
+/// #[repr(transparent)]
+/// struct Meters(i32);
+/// impl InteropReceive for Meters {
+///     type SourceType = i32;
+///     fn get_rust_rep(mono_arg: Self::SourceType) -> Self {
+///         Self(mono_arg)
+///     }
+/// }
+/// // `Meters` has the same layout as `int`.
+/// unsafe impl InteropSend for Meters {}
+/// impl InteropClass for Meters {
+///     fn get_mono_class() -> Class {
+///         Class::get_int_32()
+///     }
+/// }
+/// #[invokable]
+/// fn double_meters(meters: Meters) -> Meters {
+///     Meters(meters.0 * 2)
+/// }
+/// ```
 pub trait InteropReceive {
     ///Source type used by `MonoRuntime` when calling functions exposed by `add_internal_call`, or getting a value back from a method, that can be converted to a rust type.
     type SourceType: Copy;
@@ -21,6 +45,16 @@ pub unsafe trait InteropSend: Sized {
     fn is_class_type() -> bool {
         false
     }
+    /// Checks if methods invoked by the runtime receive this type as a pointer to an object. True for reference types and for nullable value types, which are passed boxed(or as null).
+    fn is_object_arg() -> bool {
+        Self::is_class_type()
+    }
+    /// Writes value of a value type to *dst*, in the layout the managed side stores it in(e.g. in an array element, or in a variable passed by `ref`).
+    /// # Safety
+    /// *dst* must be valid for writing the managed representation of this type.
+    unsafe fn write_to_mono(self, dst: *mut c_void) {
+        dst.cast::<Self>().write(self);
+    }
     /// Internal function used for returning values from Rust callbacks to Mono functions
     unsafe fn return_value_to_mono(mut self) -> Self {
         if Self::is_class_type() {
@@ -131,10 +165,11 @@ impl<T> InteropReceive for *const T {
         mono_arg
     }
 }
+// Managed `bool` is a 1 byte `MonoBoolean`. Any non-zero value is true, so it can't be received as rust `bool` directly.
 impl InteropReceive for bool {
-    type SourceType = Self;
+    type SourceType = crate::binds::MonoBoolean;
     fn get_rust_rep(mono_arg: Self::SourceType) -> Self {
-        mono_arg
+        mono_arg != 0
     }
 }
 impl InteropReceive for char {
@@ -184,6 +219,43 @@ unsafe impl InteropSend for String {
         true
     }
 }
+/// Trait converting tuples returned from functions marked with [`macro@crate::invokable`] to their managed `System.ValueTuple` representation.
+/// Tuples received from managed code are converted using [`InteropReceive`].
+pub trait InteropSendTuple {
+    /// Representation of the tuple with the same layout as the managed `System.ValueTuple`.
+    type TargetType;
+    /// Converts the tuple to [`Self::TargetType`], converting each element with [`InteropSend::return_value_to_mono`].
+    /// # Safety
+    /// Result must be returned to the mono runtime, see [`InteropSend::return_value_to_mono`].
+    unsafe fn tuple_to_mono(self) -> Self::TargetType;
+}
+// Implements interop for tuples, converting them to and from `System.ValueTuple` with fields laid out in order.
+macro_rules! impl_value_tuple {
+    ($name:ident, $($t:ident $idx:tt),+) => {
+        /// Representation of a managed `System.ValueTuple`, with fields laid out in the same order as `Item1`, `Item2`, etc.
+        #[repr(C)]
+        #[derive(Clone, Copy)]
+        pub struct $name<$($t),+>($(pub $t),+);
+        impl<$($t: InteropReceive),+> InteropReceive for ($($t,)+) {
+            type SourceType = $name<$($t::SourceType),+>;
+            fn get_rust_rep(mono_arg: Self::SourceType) -> Self {
+                ($($t::get_rust_rep(mono_arg.$idx),)+)
+            }
+        }
+        impl<$($t: InteropSend),+> InteropSendTuple for ($($t,)+) {
+            type TargetType = $name<$($t),+>;
+            unsafe fn tuple_to_mono(self) -> Self::TargetType {
+                $name($(self.$idx.return_value_to_mono()),+)
+            }
+        }
+    };
+}
+impl_value_tuple!(ValueTuple2, A 0, B 1);
+impl_value_tuple!(ValueTuple3, A 0, B 1, C 2);
+impl_value_tuple!(ValueTuple4, A 0, B 1, C 2, D 3);
+impl_value_tuple!(ValueTuple5, A 0, B 1, C 2, D 3, E 4);
+impl_value_tuple!(ValueTuple6, A 0, B 1, C 2, D 3, E 4, F 5);
+impl_value_tuple!(ValueTuple7, A 0, B 1, C 2, D 3, E 4, F 5, G 6);
 
 use crate::class::Class;
 /// Trait allowing for boxing and unboxing type from objects
@@ -249,10 +321,30 @@ impl<T: ObjectTrait> InteropReceive for Option<T> {
         }
     }
 }
+/// Trait converting `Option`s returned from functions marked with [`macro@crate::invokable`] to their managed representation:
+/// an object pointer(null for `None`) for objects, and [`Nullable<T>`] for value types(e.g. `int?` for `Option<i32>`).
+pub trait InteropSendOption {
+    /// Representation of the option returned to the runtime.
+    type TargetType;
+    /// Converts the option to [`Self::TargetType`].
+    /// # Safety
+    /// Result must be returned to the mono runtime, see [`InteropSend::return_value_to_mono`].
+    unsafe fn option_to_mono(self) -> Self::TargetType;
+}
+impl<T: ObjectTrait> InteropSendOption for Option<T> {
+    type TargetType = *mut crate::binds::MonoObject;
+    unsafe fn option_to_mono(mut self) -> Self::TargetType {
+        self.get_ffi_ptr().cast()
+    }
+}
 /// Trait allowing managed class representing this type to be got.
 /// Type of value `Self::InteropSend::TargetType` must match managed type represented by [`Class`] returned by `get_mono_class`.
 pub trait InteropClass {
     fn get_mono_class() -> Class;
+    /// Checks if arguments of this type are passed by reference, so they can only be passed to `ref`, `out` and `in` parameters.
+    fn is_passed_by_ref() -> bool {
+        false
+    }
 }
 impl InteropClass for i8 {
     fn get_mono_class() -> Class {
@@ -339,6 +431,11 @@ impl InteropClass for String {
         Class::get_string()
     }
 }
+impl InteropClass for &str {
+    fn get_mono_class() -> Class {
+        Class::get_string()
+    }
+}
 impl InteropBox for i8 {}
 impl InteropBox for i16 {}
 impl InteropBox for i32 {}
@@ -352,3 +449,87 @@ impl InteropBox for f64 {}
 impl InteropBox for isize {}
 impl InteropBox for usize {}
 impl InteropBox for bool {}
+/// Representation of the managed `System.Nullable<T>` struct, used to pass nullable value types(e.g. `int?`) to and from internal calls as `Option<T>`.
+/// Fields are laid out in the same order as in the Mono implementation of `Nullable<T>`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Nullable<T> {
+    value: T,
+    has_value: bool,
+}
+static NULLABLE_CLASS: std::sync::LazyLock<Class> = std::sync::LazyLock::new(|| {
+    let img = crate::Assembly::assembly_loaded("mscorlib")
+        .expect("Assembly mscorlib not loaded, could not get System.Nullable class!")
+        .get_image();
+    Class::from_name_case(&img, "System", "Nullable`1")
+        .expect("Could not get System.Nullable class form mscorlib!")
+});
+// Implements interop for `Option<T>` of boxable value types, mapping it to the managed `Nullable<T>`.
+macro_rules! impl_nullable {
+    ($($t:ty),+) => {
+        $(
+            impl InteropReceive for Option<$t> {
+                type SourceType = Nullable<$t>;
+                fn get_rust_rep(mono_arg: Self::SourceType) -> Self {
+                    mono_arg.has_value.then_some(mono_arg.value)
+                }
+            }
+            // Nullable value types are stored as `Nullable<T>`, but methods invoked by the runtime receive them boxed: as a boxed value, or null.
+            unsafe impl InteropSend for Option<$t> {
+                fn get_ffi_ptr(&mut self) -> *mut c_void {
+                    self.map_or(std::ptr::null_mut(), |value| {
+                        let domain = crate::Domain::get_current()
+                            .expect("Could not get current domain when boxing a nullable value!");
+                        crate::Object::box_val(&domain, value).get_ffi_ptr()
+                    })
+                }
+                fn is_object_arg() -> bool {
+                    true
+                }
+                unsafe fn write_to_mono(self, dst: *mut c_void) {
+                    dst.cast::<Nullable<$t>>().write(self.option_to_mono());
+                }
+            }
+            impl InteropSendOption for Option<$t> {
+                type TargetType = Nullable<$t>;
+                unsafe fn option_to_mono(self) -> Self::TargetType {
+                    match self {
+                        Some(value) => Nullable {
+                            value,
+                            has_value: true,
+                        },
+                        // Value of an empty `Nullable<T>` is never read, so it can be zeroed.
+                        None => Nullable {
+                            value: std::mem::zeroed(),
+                            has_value: false,
+                        },
+                    }
+                }
+            }
+            impl InteropClass for Option<$t> {
+                fn get_mono_class() -> Class {
+                    NULLABLE_CLASS
+                        .bind_generic_params(&[<$t>::get_mono_class()])
+                        .expect("Could not create a Nullable<T> class!")
+                }
+            }
+        )+
+    };
+}
+impl_nullable!(
+    i8,
+    i16,
+    i32,
+    i64,
+    u8,
+    u16,
+    u32,
+    u64,
+    f32,
+    f64,
+    isize,
+    usize,
+    bool,
+    crate::Guid,
+    crate::DateTime
+);
@@ -1,9 +1,15 @@
-use crate::binds::{mono_jit_cleanup, mono_jit_exec, mono_jit_init, mono_jit_init_version};
+use crate::binds::{
+    mono_bool, mono_jit_cleanup, mono_jit_exec, mono_jit_init, mono_jit_init_version, MonoAssembly,
+    MonoAssemblyName, MonoObject,
+};
 use crate::domain::Domain;
-use std::ffi::CString;
+use crate::{Exception, ObjectTrait};
+use std::ffi::{c_char, c_void, CStr, CString};
+use std::sync::{Mutex, Once};
 static mut HAS_BEEN_INITIALIZED: bool = false;
 /// This function starts up MonoRuntime,and returns main domain. It should be called before any other mono function is called. **Can be only called once per process.**
 /// Version argument specifies runtime version, if **None** passed, default version will be selected.
+/// Unknown versions are silently replaced with the default one, use [`init_strict`] to detect that.
 /// ```no_run
 /// # use wrapped_mono::*;
 /// let main_domain = jit::init("domain_name",None);
@@ -38,6 +44,93 @@ pub fn init(name: &str, version: Option<&str>) -> Domain {
     let _ = &n_cstr;
     res
 }
+/// Starts up the runtime like [`init`] with *version*, but checks if the runtime actually uses the requested version.
+/// When asked for an unknown version, the runtime silently falls back to its default version. This function reports such fallback as an error instead.
+///
+/// Components of *version* which are present(e.g. `v4.0` or `v4.0.30319`) are compared with version of the loaded core library.
+/// # Example
+/// ```no_run
+/// # use wrapped_mono::*;
+/// let main_domain = jit::init_strict("main","v4.0.30319").expect("Required runtime version is not available!");
+/// ```
+/// # Errors
+/// Returns [`RuntimeVersionError`] if the runtime does not use *version*. The runtime is still initialised in that case, and can't be initialised again.
+/// # Panics
+/// Panics if the runtime is initialised second time. `MonoRuntime` can be only initialised once.
+pub fn init_strict(name: &str, version: &str) -> Result<Domain, RuntimeVersionError> {
+    let domain = init(name, Some(version));
+    let actual = corlib_version();
+    let requested = version.strip_prefix('v').unwrap_or(version);
+    let mut actual_parts = actual.split('.');
+    let matches = !requested.is_empty()
+        && requested.split('.').all(|part| {
+            part.parse::<u32>().ok() == actual_parts.next().and_then(|a| a.parse::<u32>().ok())
+        });
+    if matches {
+        Ok(domain)
+    } else {
+        Err(RuntimeVersionError {
+            requested: version.to_owned(),
+            actual,
+        })
+    }
+}
+/// Returns version of the runtime, including build information(e.g. `6.12.0.182 (tarball Wed Jun 15 2022)`).
+/// # Example
+/// ```no_run
+/// # use wrapped_mono::*;
+/// println!("Running on mono {}",jit::runtime_version());
+/// ```
+#[must_use]
+pub fn runtime_version() -> String {
+    let ptr = unsafe { crate::binds::mono_get_runtime_build_info() };
+    let res = unsafe { CStr::from_ptr(ptr) }
+        .to_str()
+        .expect(crate::CSTR2STR_ERR)
+        .to_owned();
+    unsafe { crate::binds::mono_free(ptr.cast()) };
+    res
+}
+/// Returns version of the loaded core library, as reported by `System.Environment.Version`(e.g. `4.0.30319.42000`).
+/// # Panics
+/// Panics if called before [`init`].
+#[must_use]
+pub fn corlib_version() -> String {
+    let environment = crate::Class::from_name(
+        &crate::Class::get_object().get_image(),
+        "System",
+        "Environment",
+    )
+    .expect("Could not find class System.Environment!");
+    environment
+        .get_property_from_name("Version")
+        .expect("Could not find property System.Environment.Version!")
+        .get_value(None)
+        .expect("Got an exception while getting runtime version!")
+        .expect("Runtime version is null!")
+        .to_mstring()
+        .expect("Got an exception while converting runtime version to string!")
+        .expect("Runtime version string is null!")
+        .to_string()
+}
+/// Error returned by [`init_strict`] when the runtime does not use the requested version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeVersionError {
+    /// Version passed to [`init_strict`].
+    pub requested: String,
+    /// Version the runtime actually uses.
+    pub actual: String,
+}
+impl std::fmt::Display for RuntimeVersionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "requested runtime version {}, but runtime uses version {}",
+            self.requested, self.actual
+        )
+    }
+}
+impl std::error::Error for RuntimeVersionError {}
 /// This function shuts down the `MonoRuntime`.
 /// **WARNING!** after it is called, `MonoRuntime` **will not be** able to be used again in the same process, since it can be only started up once.
 /// ```no_run
@@ -49,7 +142,7 @@ pub fn init(name: &str, version: Option<&str>) -> Domain {
 pub fn cleanup(domain: Domain) {
     unsafe { mono_jit_cleanup(domain.get_ptr()) };
 }
-use crate::assembly::Assembly;
+use crate::assembly::{Assembly, AssemblyName};
 /// Function used to call main function from assembly in domain with arguments.
 /// ```ignore
 /// //C# code in file "SomeAssembly.dll"
@@ -98,3 +191,213 @@ pub fn exec(domain: &Domain, assembly: &Assembly, args: Vec<&str>) -> Result<i32
     let _ = &cstr_args;
     Ok(res)
 }
+/// Severity of a message logged by the runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    /// Fatal error, runtime will abort after the message is handled.
+    Error,
+    /// Critical, but not fatal error.
+    Critical,
+    /// Warning.
+    Warning,
+    /// Normal message.
+    Message,
+    /// Informational message.
+    Info,
+    /// Debug message.
+    Debug,
+}
+impl LogLevel {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "error" => Some(Self::Error),
+            "critical" => Some(Self::Critical),
+            "warning" => Some(Self::Warning),
+            "message" => Some(Self::Message),
+            "info" => Some(Self::Info),
+            "debug" => Some(Self::Debug),
+            _ => None,
+        }
+    }
+    fn name(self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Critical => "critical",
+            Self::Warning => "warning",
+            Self::Message => "message",
+            Self::Info => "info",
+            Self::Debug => "debug",
+        }
+    }
+}
+type LogHandler = Box<dyn Fn(LogLevel, &str) + Send>;
+static LOG_HANDLER: Mutex<Option<LogHandler>> = Mutex::new(None);
+unsafe extern "C" fn log_handler_trampoline(
+    _log_domain: *const c_char,
+    log_level: *const c_char,
+    message: *const c_char,
+    _fatal: mono_bool,
+    _user_data: *mut c_void,
+) {
+    if log_level.is_null() || message.is_null() {
+        return;
+    }
+    // Runtime only uses known level names, but unknown ones should not be dropped.
+    let level = LogLevel::from_name(&CStr::from_ptr(log_level).to_string_lossy())
+        .unwrap_or(LogLevel::Message);
+    let message = CStr::from_ptr(message).to_string_lossy();
+    if let Some(handler) = LOG_HANDLER
+        .lock()
+        .expect("Log handler mutex poisoned!")
+        .as_ref()
+    {
+        handler(level, &message);
+    }
+}
+/// Routes messages logged by the runtime to *handler* instead of printing them to standard error. Replaces previously set handler.
+/// Can be called before [`init`], to also receive messages logged during initialization.
+///
+/// *handler* must not panic and must not call into the runtime in a way that could cause it to log, since it is called while the handler lock is held.
+/// # Example
+/// ```no_run
+/// # use wrapped_mono::*;
+/// jit::set_log_handler(|level, message| println!("[mono {level:?}] {message}"));
+/// let main_domain = jit::init("main",None);
+/// ```
+pub fn set_log_handler(handler: impl Fn(LogLevel, &str) + Send + 'static) {
+    *LOG_HANDLER.lock().expect("Log handler mutex poisoned!") = Some(Box::new(handler));
+    unsafe {
+        crate::binds::mono_trace_set_log_handler(
+            Some(log_handler_trampoline),
+            std::ptr::null_mut(),
+        );
+    };
+}
+/// Sets the lowest severity of messages logged by the runtime. By default only [`LogLevel::Error`] messages are logged.
+pub fn set_log_level(level: LogLevel) {
+    let cstr = CString::new(level.name()).expect(crate::STR2CSTR_ERR);
+    unsafe { crate::binds::mono_trace_set_level_string(cstr.as_ptr()) };
+}
+type PrintHandler = Box<dyn Fn(&str) + Send>;
+static PRINT_HANDLER: Mutex<Option<PrintHandler>> = Mutex::new(None);
+static PRINTERR_HANDLER: Mutex<Option<PrintHandler>> = Mutex::new(None);
+unsafe fn call_print_handler(handler: &Mutex<Option<PrintHandler>>, string: *const c_char) {
+    if string.is_null() {
+        return;
+    }
+    let string = CStr::from_ptr(string).to_string_lossy();
+    if let Some(handler) = handler
+        .lock()
+        .expect("Print handler mutex poisoned!")
+        .as_ref()
+    {
+        handler(&string);
+    }
+}
+unsafe extern "C" fn print_trampoline(string: *const c_char, _is_stdout: mono_bool) {
+    call_print_handler(&PRINT_HANDLER, string);
+}
+unsafe extern "C" fn printerr_trampoline(string: *const c_char, _is_stdout: mono_bool) {
+    call_print_handler(&PRINTERR_HANDLER, string);
+}
+/// Routes text the runtime itself prints to standard output(*out*) and standard error(*err*) to the given handlers. Replaces previously set handlers.
+///
+/// Only output of the runtime is redirected: managed code writing through `System.Console` writes to the process streams directly and is **not** passed to the handlers.
+/// Handlers must not panic and must not print through the runtime, since they are called while the handler lock is held.
+/// # Example
+/// ```no_run
+/// # use wrapped_mono::*;
+/// jit::set_print_handler(|text| print!("[mono] {text}"), |text| eprint!("[mono] {text}"));
+/// let main_domain = jit::init("main",None);
+/// ```
+pub fn set_print_handler(out: impl Fn(&str) + Send + 'static, err: impl Fn(&str) + Send + 'static) {
+    *PRINT_HANDLER.lock().expect("Print handler mutex poisoned!") = Some(Box::new(out));
+    *PRINTERR_HANDLER
+        .lock()
+        .expect("Print handler mutex poisoned!") = Some(Box::new(err));
+    unsafe {
+        crate::binds::mono_trace_set_print_handler(Some(print_trampoline));
+        crate::binds::mono_trace_set_printerr_handler(Some(printerr_trampoline));
+    };
+}
+type AssemblyPreloadHook = Box<dyn Fn(&AssemblyName) -> Option<Assembly> + Send>;
+static ASSEMBLY_PRELOAD_HOOK: Mutex<Option<AssemblyPreloadHook>> = Mutex::new(None);
+static INSTALL_PRELOAD_HOOK: Once = Once::new();
+unsafe extern "C" fn assembly_preload_trampoline(
+    aname: *mut MonoAssemblyName,
+    _assemblies_path: *mut *mut c_char,
+    _user_data: *mut c_void,
+) -> *mut MonoAssembly {
+    let name = AssemblyName::from_name_ptr(aname);
+    ASSEMBLY_PRELOAD_HOOK
+        .lock()
+        .expect("Assembly preload hook mutex poisoned!")
+        .as_ref()
+        .and_then(|hook| hook(&name))
+        .map_or(std::ptr::null_mut(), |assembly| assembly.get_ptr())
+}
+/// Sets *hook* called when an assembly is requested by name(e.g. a referenced assembly is needed, or `System.Reflection.Assembly.Load` is called). Replaces previously set hook.
+///
+/// The hook runs before the runtime searches for the assembly itself. If it returns [`Some`], that assembly is used, if it returns [`None`], the default search continues.
+/// This allows hosts to load assemblies from custom storage(databases, archives). Assemblies should be loaded by path(e.g. with [`Assembly::open`]) inside the hook,
+/// since loading an assembly by name would call the hook again.
+/// # Example
+/// ```no_run
+/// # use wrapped_mono::*;
+/// let main_domain = jit::init("main",None);
+/// jit::install_assembly_preload_hook(|name| {
+///     Assembly::open(&format!("plugins/{}.dll", name.get_name())).ok()
+/// });
+/// ```
+pub fn install_assembly_preload_hook(
+    hook: impl Fn(&AssemblyName) -> Option<Assembly> + Send + 'static,
+) {
+    *ASSEMBLY_PRELOAD_HOOK
+        .lock()
+        .expect("Assembly preload hook mutex poisoned!") = Some(Box::new(hook));
+    // The runtime keeps a list of preload hooks, so the trampoline must be installed only once.
+    INSTALL_PRELOAD_HOOK.call_once(|| unsafe {
+        crate::binds::mono_install_assembly_preload_hook(
+            Some(assembly_preload_trampoline),
+            std::ptr::null_mut(),
+        );
+    });
+}
+type UnhandledExceptionHook = Box<dyn Fn(Exception) + Send>;
+static UNHANDLED_EXCEPTION_HOOK: Mutex<Option<UnhandledExceptionHook>> = Mutex::new(None);
+unsafe extern "C" fn unhandled_exception_trampoline(exc: *mut MonoObject, _user_data: *mut c_void) {
+    let Some(exception) = Exception::from_ptr(exc) else {
+        return;
+    };
+    if let Some(hook) = UNHANDLED_EXCEPTION_HOOK
+        .lock()
+        .expect("Unhandled exception hook mutex poisoned!")
+        .as_ref()
+    {
+        hook(exception);
+    }
+}
+/// Sets *hook* called with managed exceptions nobody handled(e.g. thrown on a managed thread and never caught). Replaces previously set hook.
+///
+/// The runtime treats unhandled exceptions as fatal: after *hook* returns, the process is aborted. The hook is meant for reporting the exception(logging it, writing a crash report),
+/// and may end the process itself(e.g. with [`std::process::exit`]) to choose the exit code. It must not panic.
+/// # Example
+/// ```no_run
+/// # use wrapped_mono::*;
+/// let main_domain = jit::init("main",None);
+/// jit::set_unhandled_exception_hook(|exception| {
+///     eprintln!("Unhandled {}:{}", exception.class_name(), exception.message());
+///     std::process::exit(1);
+/// });
+/// ```
+pub fn set_unhandled_exception_hook(hook: impl Fn(Exception) + Send + 'static) {
+    *UNHANDLED_EXCEPTION_HOOK
+        .lock()
+        .expect("Unhandled exception hook mutex poisoned!") = Some(Box::new(hook));
+    unsafe {
+        crate::binds::mono_install_unhandled_exception_hook(
+            Some(unhandled_exception_trampoline),
+            std::ptr::null_mut(),
+        );
+    };
+}
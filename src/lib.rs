@@ -80,26 +80,34 @@ pub mod assembly;
 pub mod binds;
 /// Representation of managed classes and utilities related to them.
 pub mod class;
+/// Representation of the managed `System.DateTime` struct.
+pub mod datetime;
 /// Safe representation of a delegate.
-// pub mod delegate;
+pub mod delegate;
 /// Functions and types related to `MonoDomain` type.
 pub mod domain;
 ///Utilities related to Exceptions.
 pub mod exception;
 /// Functions related to garbage collection.
 pub mod gc;
+/// Representation of the managed `System.Guid` struct.
+pub mod guid;
 /// Part of assembly holding the executable code.
 pub mod image;
 /// Traits related to passing data between managed and unmanaged classes.
 pub mod interop;
 /// Functions related to Mono JIT Runtime
 pub mod jit;
+/// Passing byte buffers to managed code as `System.IO.MemoryStream`.
+pub mod memorystream;
 /// Utilities related to metadata. Bare bones and experimental.
 pub mod metadata;
 /// Safe representation of Methods(functions) form managed code an utilities related to managing and calling them.
 pub mod method;
 /// Managed string utilities.
 pub mod mstring;
+/// Safe representation of unmanaged type descriptions.
+pub mod mtype;
 /// Utilities related to managed objects.
 pub mod object;
 /// Experimental Profiler API. Bare bones and may contain bugs.
@@ -110,40 +118,52 @@ pub mod profiler;
 pub mod reflection_type;
 ///Functions related to getting data about and configuring mono runtime.
 pub mod runtime;
+/// Utilities related to managed threads and attaching native threads to the runtime.
+pub mod thread;
+/// Safe representation of per-domain runtime data of a class.
+pub mod vtable;
 
 mod testing;
 mod tupleutilis; // Some utility traits used internally.
 
 #[doc(inline)]
-pub use array::Array;
+pub use array::{Array, ArrayIndexError, ArrayIter};
 #[doc(inline)]
-pub use assembly::Assembly;
+pub use assembly::{Assembly, AssemblyLoadError, AssemblyName};
 #[doc(inline)]
-pub use class::{Class, ClassField, ClassProperty};
+pub use class::{Class, ClassCache, ClassEvent, ClassField, ClassLookupError, ClassProperty};
 #[doc(inline)]
-// pub use delegate::{Delegate, DelegateTrait};
+pub use datetime::DateTime;
 #[doc(inline)]
-pub use domain::Domain;
+pub use delegate::{Delegate, InvokeArg, MethodArgs};
+#[doc(inline)]
+pub use domain::{CreateError, Domain};
 #[doc(inline)]
 pub use exception::Exception;
 #[doc(inline)]
+pub use guid::Guid;
+#[doc(inline)]
 pub use image::Image;
 #[doc(inline)]
 pub use interop::{InteropBox, InteropClass, InteropReceive, InteropSend};
 #[doc(inline)]
-pub use method::Method;
+pub use method::{InvokeArgRef, Method, MethodHeader};
 #[doc(inline)]
 pub use mstring::MString;
 #[doc(inline)]
-pub use object::{Object, ObjectTrait};
+pub use mtype::{Type, TypeNameFormat};
+#[doc(inline)]
+pub use object::{Object, ObjectTrait, RootedObject};
 #[doc(inline)]
 pub use reflection_type::ReflectionType;
+#[doc(inline)]
+pub use vtable::VTable;
 
 #[doc(inline)]
 /// Custom macros used by `wrapped_mono`
 pub use wrapped_mono_macros; // Custom macros
 #[doc(inline)]
-pub use wrapped_mono_macros::{add_internal_call, invokable, InteropReceive};
+pub use wrapped_mono_macros::{add_internal_call, invokable, InteropReceive, MonoBlittable};
 
 static STR2CSTR_ERR: &str = "Cold not create CString!";
 static CSTR2STR_ERR: &str = "Could not convert CString to String";
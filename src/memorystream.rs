@@ -0,0 +1,65 @@
+use crate::{Array, Class, Dim1D, Domain, Method, Object, ObjectTrait};
+use std::sync::LazyLock;
+/// Creates a new `System.IO.MemoryStream` in *domain* containing a copy of *bytes*. Position of the returned stream is set to its beginning.
+/// # Example
+/// ```no_run
+/// # use wrapped_mono::*;
+/// # let domain = Domain::get_current().unwrap();
+/// let stream = memorystream::from_bytes(&domain,&[0,1,2,3]);
+/// assert_eq!(memorystream::to_bytes(&stream),vec![0,1,2,3]);
+/// ```
+/// # Panics
+/// Panics if the managed `MemoryStream` could not be created or written to.
+#[must_use]
+pub fn from_bytes(domain: &Domain, bytes: &[u8]) -> Object {
+    let class = *MEMORY_STREAM_CLASS;
+    let stream = Object::new(domain, &class);
+    let ctor: Method<()> = Method::get_from_name(&class, ".ctor", 0)
+        .expect("MemoryStream has no parameterless constructor!");
+    ctor.invoke(Some(stream.clone()), ())
+        .expect("Got an exception while constructing a MemoryStream!");
+    let mut buffer: Array<Dim1D, u8> = Array::new(domain, &[bytes.len()]);
+    for (i, byte) in bytes.iter().enumerate() {
+        buffer.set([i], *byte);
+    }
+    let write: Method<(Array<Dim1D, u8>, i32, i32)> = Method::get_from_name(&class, "Write", 3)
+        .expect("Could not find MemoryStream.Write(byte[],int,int)!");
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    write
+        .invoke(Some(stream.clone()), (buffer, 0, bytes.len() as i32))
+        .expect("Got an exception while writing to a MemoryStream!");
+    let set_position: Method<(i64,)> = Method::get_from_name(&class, "set_Position", 1)
+        .expect("Could not find MemoryStream.Position setter!");
+    set_position
+        .invoke(Some(stream.clone()), (0,))
+        .expect("Got an exception while rewinding a MemoryStream!");
+    stream
+}
+/// Reads whole content of a `System.IO.MemoryStream` *stream*, regardless of its current position.
+/// # Panics
+/// Panics if *stream* is not a `MemoryStream`, or if calling `ToArray` on it throws.
+#[must_use]
+pub fn to_bytes(stream: &Object) -> Vec<u8> {
+    let class = *MEMORY_STREAM_CLASS;
+    assert!(
+        class.is_assignable_from(&stream.get_class()),
+        "Tried to read bytes from object of type `{}`, which is not a MemoryStream!",
+        stream.get_class().get_name_sig()
+    );
+    let to_array: Method<()> =
+        Method::get_from_name(&class, "ToArray", 0).expect("Could not find MemoryStream.ToArray!");
+    let array: Array<Dim1D, u8> = to_array
+        .invoke(Some(stream.clone()), ())
+        .expect("Got an exception while calling MemoryStream.ToArray!")
+        .expect("MemoryStream.ToArray returned null!")
+        .cast()
+        .expect("MemoryStream.ToArray did not return a byte[]!");
+    (0..array.len()).map(|i| array.get([i])).collect()
+}
+static MEMORY_STREAM_CLASS: LazyLock<Class> = LazyLock::new(|| {
+    let img = crate::Assembly::assembly_loaded("mscorlib")
+        .expect("Assembly mscorlib not loaded, could not get System.IO.MemoryStream class!")
+        .get_image();
+    Class::from_name_case(&img, "System.IO", "MemoryStream")
+        .expect("Could not get System.IO.MemoryStream class form mscorlib!")
+});
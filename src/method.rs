@@ -1,8 +1,10 @@
-use crate::binds::{MonoException, MonoMethod, MonoObject};
+use crate::binds::{MonoMethod, MonoMethodHeader, MonoObject};
+use crate::interop::{InteropClass, InteropReceive};
 use crate::tupleutilis::{CompareClasses, TupleToFFIPtrs};
-use crate::{Class, Exception, InteropSend, Object, ObjectTrait};
+use crate::{Class, Exception, InteropSend, MethodArgs, Object, ObjectTrait};
 use core::{ffi::c_void, marker::PhantomData};
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
+use std::mem::MaybeUninit;
 use std::ptr::null_mut;
 //Depends on: #![feature(specialization)]
 /// Rust representation of a managed method(function of code loaded into mono runtime).
@@ -71,8 +73,7 @@ impl<Args: TupleToFFIPtrs + CompareClasses> Method<Args> {
     pub fn can_access_method<T: TupleToFFIPtrs + CompareClasses>(&self, called: &Method<T>) -> bool {
         (unsafe { crate::binds::mono_method_can_access_method(self.method, called.method) } != 0)
     }
-    ///Metadata token. Not working without MetadataAPI
-    #[doc(hidden)]
+    /// Returns metadata token of this method. For methods defined in an assembly it is a `MethodDef` token(`0x06xxxxxx`), with the low 24 bits being the row in the `MethodDef` table.
     #[must_use]
     pub fn get_token(&self) -> u32 {
         unsafe { crate::binds::mono_method_get_token(self.method) }
@@ -117,6 +118,84 @@ impl<Args: TupleToFFIPtrs + CompareClasses> Method<Args> {
         drop(cstr);
         res
     }
+    // Gets the first method declared in *class* named *name*, whose parameters match *Args*. Unlike `get_from_name` considers all overloads.
+    pub(crate) fn find_overload(class: &Class, name: &str) -> Option<Self> {
+        let mut iter = std::ptr::null_mut::<c_void>();
+        loop {
+            let ptr = unsafe {
+                crate::binds::mono_class_get_methods(class.get_ptr(), std::ptr::addr_of_mut!(iter))
+            };
+            if ptr.is_null() {
+                return None;
+            }
+            let method_name = unsafe { CStr::from_ptr(crate::binds::mono_method_get_name(ptr)) };
+            if method_name.to_bytes() != name.as_bytes() {
+                continue;
+            }
+            if let Some(method) = unsafe { Self::from_ptr(ptr) } {
+                return Some(method);
+            }
+        }
+    }
+    // Returns, for each parameter, if it is passed by reference(`ref`, `out` and `in` parameters).
+    fn get_params_byref(&self) -> Vec<bool> {
+        let sig = unsafe { crate::binds::mono_method_signature(self.method) };
+        let mut iter: usize = 0;
+        let mut res = Vec::with_capacity(self.get_param_count() as usize);
+        loop {
+            let ptr = unsafe {
+                crate::binds::mono_signature_get_params(
+                    sig,
+                    std::ptr::addr_of_mut!(iter).cast::<*mut c_void>(),
+                )
+            };
+            if ptr.is_null() {
+                return res;
+            }
+            res.push(unsafe { crate::binds::mono_type_is_byref(ptr) } != 0);
+        }
+    }
+    /// Gets name of method *`self`*.
+    #[must_use]
+    pub fn get_name(&self) -> String {
+        // Name is owned by the runtime, so it must not be freed.
+        unsafe { CStr::from_ptr(crate::binds::mono_method_get_name(self.method)) }
+            .to_str()
+            .expect(crate::CSTR2STR_ERR)
+            .to_owned()
+    }
+    /// Returns header of this method, describing its IL body. Returns [`None`] for methods without IL body(e.g. abstract, runtime or internal call methods).
+    #[must_use]
+    pub fn get_header(&self) -> Option<MethodHeader> {
+        let header = unsafe { crate::binds::mono_method_get_header(self.method) };
+        if header.is_null() {
+            return None;
+        }
+        Some(MethodHeader { header })
+    }
+    /// Returns instances of custom attributes this method is decorated with. If *`attr_class`* is [`Some`], returns only attributes of that class(or classes deriving from it).
+    /// # Example
+    /// ## C#
+    ///```csharp
+    /// [Benchmark(100)]
+    /// public static void SomeFunction(){}
+    ///```
+    /// ## Rust
+    ///```no_run
+    /// # use wrapped_mono::*;
+    /// # let method:Method<()> = todo!();
+    /// # let benchmark_attribute_class:Class = todo!();
+    /// let benchmark_attributes = method.get_custom_attributes(Some(&benchmark_attribute_class));
+    ///```
+    #[must_use]
+    pub fn get_custom_attributes(&self, attr_class: Option<&Class>) -> Vec<Object> {
+        unsafe {
+            crate::class::construct_custom_attrs(
+                crate::binds::mono_custom_attrs_from_method(self.method),
+                attr_class,
+            )
+        }
+    }
     /// Gets names of all parameters method *`self`* accepts.
     /// # Arguments
     /// |Name   |Type   |Description|
@@ -170,6 +249,60 @@ impl<Args: TupleToFFIPtrs + CompareClasses> Method<Args> {
         }
         res
     }
+    /// Returns number of generic arguments of this method. For open generic definitions it is the number of generic parameters,
+    /// and for closed instantiations the number of type arguments. Returns 0 for non-generic methods.
+    /// # Panics
+    /// Panics if called before JIT is initialized.
+    #[must_use]
+    pub fn generic_argument_count(&self) -> u32 {
+        let domain = crate::Domain::get_current()
+            .expect("Can't get reflection object of a method before JIT starts!");
+        crate::reflection_type::generic_arguments_len(&self.get_reflection_object(&domain))
+    }
+    /// Returns managed reflection object(`System.Reflection.MethodInfo`, or `System.Reflection.ConstructorInfo` for constructors) representing this method in *domain*.
+    /// It can be passed to managed APIs expecting a `MethodInfo`.
+    /// # Example
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// # let method:Method<()> = todo!();
+    /// # let domain = Domain::get_current().unwrap();
+    /// let method_info = method.get_reflection_object(&domain);
+    /// ```
+    #[must_use]
+    pub fn get_reflection_object(&self, domain: &crate::Domain) -> Object {
+        #[cfg(feature = "referenced_objects")]
+        let marker = crate::gc::gc_unsafe_enter();
+        let res = unsafe {
+            Object::from_ptr(
+                crate::binds::mono_method_get_object(domain.get_ptr(), self.method, null_mut())
+                    .cast(),
+            )
+        }
+        .expect("Could not get reflection object of a method!");
+        #[cfg(feature = "referenced_objects")]
+        crate::gc::gc_unsafe_exit(marker);
+        res
+    }
+    /// Checks if method *`self`* can be invoked with arguments of classes *`arg_classes`*: argument count must match, and each argument must be assignable to its parameter.
+    /// Useful for validating arguments before calling a method.
+    /// # Example
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// # fn check(method:&Method<(Object,)>){
+    /// if !method.can_invoke_with(&[Class::get_string()]){
+    ///     eprintln!("Method can't be called with a string!");
+    /// }
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn can_invoke_with(&self, arg_classes: &[Class]) -> bool {
+        let params = self.get_params();
+        params.len() == arg_classes.len()
+            && params
+                .iter()
+                .zip(arg_classes)
+                .all(|(param, arg)| param.is_assignable_from(arg))
+    }
     /// Returns the return type of method *`self`*, if no return type returns *`System.Void`*
     /// # Arguments
     /// |Name   |Type   |Description|
@@ -183,6 +316,40 @@ impl<Args: TupleToFFIPtrs + CompareClasses> Method<Args> {
             Class::from_ptr(crate::binds::mono_class_from_mono_type(ptr)).expect("Got no method return type, but no return type should be signaled by System.Void type!")
         }
     }
+    /// Returns a native function pointer(thunk) calling this method directly, avoiding the overhead of [`Self::invoke`].
+    /// Useful when the same method is called many times.
+    ///
+    /// Thunk takes the arguments of the method(preceded by the object to invoke it on if the method is not static), followed by a `*mut *mut MonoException`
+    /// which is set to the thrown exception, if any. Arguments of reference types are passed as `*mut MonoObject`, and arguments of value types as their values.
+    /// # Safety
+    /// *F* must be an `extern "C"`(`extern "stdcall"` on Windows) function pointer type with a signature **exactly** matching the managed method,
+    /// including the trailing `*mut *mut MonoException` argument. Thunk may only be called from threads attached to the runtime.
+    /// # Example
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// # use wrapped_mono::binds::MonoException;
+    /// # let class = Class::get_int_32();
+    /// // Managed signature: static int GetArg(int arg)
+    /// let met:Method<(i32,)> = Method::get_from_name(&class,"GetArg",1).unwrap();
+    /// let thunk:extern "C" fn(i32,*mut *mut MonoException)->i32 = unsafe{met.get_thunk()};
+    /// let mut exception = std::ptr::null_mut();
+    /// let res = thunk(5,&mut exception);
+    /// assert!(exception.is_null());
+    /// ```
+    #[must_use]
+    pub unsafe fn get_thunk<F: Copy>(&self) -> F {
+        assert_eq!(
+            std::mem::size_of::<F>(),
+            std::mem::size_of::<*mut c_void>(),
+            "Thunk type must be a function pointer!"
+        );
+        let thunk = crate::binds::mono_method_get_unmanaged_thunk(self.method);
+        assert!(
+            !thunk.is_null(),
+            "Could not get unmanaged thunk of a method!"
+        );
+        std::mem::transmute_copy::<*mut c_void, F>(&thunk)
+    }
 }
 impl<Args: CompareClasses + TupleToFFIPtrs> Method<Args> {
     /// Invoke this method on object *`object`* with arguments *`args`*
@@ -192,6 +359,8 @@ impl<Args: CompareClasses + TupleToFFIPtrs> Method<Args> {
     /// |`self`   | `&Self`|Reference to method to invoke. |
     /// |`object` | [`Option<Object>`] |Object to invoke method on. Pass [`None`] if method is static. |
     /// |`args`   | `Args`|Arguments to pass to method |
+    ///
+    /// Arguments of `ref` and `out` parameters must be wrapped in [`InvokeArgRef`]. Values assigned to them by managed code are written back when the call finishes.
     /// # Errors
     /// Returns an exception if it was thrown by managed code.
     pub fn invoke(
@@ -199,35 +368,198 @@ impl<Args: CompareClasses + TupleToFFIPtrs> Method<Args> {
         object: Option<Object>,
         mut args: Args,
     ) -> Result<Option<Object>, Exception> {
-        //convert object to invoke on to a pointer.
-        let obj_ptr = object.map_or(core::ptr::null_mut(), |obj| obj.get_ptr());
-        let mut expect: *mut MonoException = null_mut();
-        //convert argument types
-
-        let mut params = args.get_ptrs();
+        //convert argument types, keeping objects created for them alive until the call returns.
+        let mut roots = Vec::new();
+        let mut params = args.get_ptrs(&mut roots);
         //invoke the method itself
-        let res_ptr = unsafe {
-            crate::binds::mono_runtime_invoke(
-                self.get_ptr(),
-                obj_ptr.cast::<std::os::raw::c_void>(),
-                std::ptr::addr_of_mut!(params).cast::<*mut c_void>(),
-                std::ptr::addr_of_mut!(expect).cast::<*mut MonoObject>(),
-            )
-        };
+        let (res, except) = unsafe { self.invoke_raw(object.as_ref(), params.as_mut()) };
         //ensure args lives  as long as params lives.
         let _ = &args;
-        //get result
-        let res = unsafe { Object::from_ptr(res_ptr) };
-        if expect.is_null() {
-            Ok(res)
-        } else {
-            let except = unsafe {
-                Exception::from_ptr(expect.cast())
-                    .expect("Impossible: pointer is null and not null at the same time.")
-            };
-            Err(except)
+        drop(roots);
+        match except {
+            None => Ok(res),
+            Some(except) => Err(unsafe { Exception::from_ptr(except.get_ptr()) }
+                .expect("Managed code threw an object which is not an exception!")),
         }
     }
+    /// Creates a concrete instance of this generic method with type arguments *`type_args`*(e.g. `Identity<int>` from `Identity<T>` and `int`), which can be invoked.
+    /// *self* must be a generic method definition. Uses `System.Reflection.MethodInfo.MakeGenericMethod`, so it returns [`None`] if *self* is not a generic method definition,
+    /// number of *`type_args`* does not match number of generic parameters, they do not satisfy constraints of the generic parameters, or *`InflatedArgs`* do not match parameters of the created method.
+    /// # Example
+    /// ## C#
+    /// ```csharp
+    /// class SomeClass{
+    ///     public static T Identity<T>(T value){
+    ///         return value;
+    ///     }
+    /// }
+    /// ```
+    /// ## Rust
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// # let some_class:Class = todo!();
+    /// let identity:Method<(Object,)> = Method::get_from_name(&some_class,"Identity",1).expect("Could not find method!");
+    /// let int_identity:Method<(i32,)> = identity.inflate(&[Class::get_int_32()]).expect("Could not inflate method!");
+    /// let seven = int_identity.invoke(None,(7,)).expect("Got an exception!").expect("Got null!").unbox::<i32>();
+    /// ```
+    #[must_use]
+    pub fn inflate<InflatedArgs: TupleToFFIPtrs + CompareClasses>(
+        &self,
+        type_args: &[Class],
+    ) -> Option<Method<InflatedArgs>> {
+        use crate::{Array, Dim1D, ReflectionType};
+        let domain =
+            crate::Domain::get_current().expect("Can't inflate a method before JIT started.");
+        let mut types: Array<Dim1D, ReflectionType> = Array::new(&domain, &[type_args.len()]);
+        for (index, arg) in type_args.iter().enumerate() {
+            types.set([index], ReflectionType::from_class(arg));
+        }
+        let method_info = unsafe {
+            let class = crate::binds::mono_method_get_class(self.method);
+            Object::from_ptr(
+                crate::binds::mono_method_get_object(domain.get_ptr(), self.method, class).cast(),
+            )
+        }?;
+        let inflated = method_info.call("MakeGenericMethod", (types,)).ok()??;
+        method_from_method_info(&inflated)
+    }
+    /// Invokes this method on object *`object`* with arguments *`args`* built using [`MethodArgs`].
+    /// # Example
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// # let method:Method<(i32,String)> = todo!();
+    /// let args = MethodArgs::new().add(1_i32).add("one");
+    /// let res = method.invoke_args(None,&args).expect("Got an exception!");
+    /// ```
+    /// # Errors
+    /// Returns an exception if it was thrown by managed code, or `System.MissingMethodException` if the method can't be invoked with *args*.
+    pub fn invoke_args(
+        &self,
+        object: Option<Object>,
+        args: &MethodArgs,
+    ) -> Result<Option<Object>, Exception> {
+        let args = args.as_refs();
+        if !crate::delegate::args_match(&self.get_params(), &args) {
+            let class =
+                unsafe { Class::from_ptr(crate::binds::mono_method_get_class(self.method)) }
+                    .expect("Method has no class!");
+            return Err(Exception::missing_method(
+                &class.get_name(),
+                &self.get_name(),
+            ));
+        }
+        let (params, roots) = crate::delegate::invoke_ptrs(&args);
+        let (res, except) = unsafe { self.invoke_raw(object.as_ref(), &params) };
+        drop(roots);
+        match except {
+            None => Ok(res),
+            Some(except) => Err(unsafe { Exception::from_ptr(except.get_ptr()) }
+                .expect("Managed code threw an object which is not an exception!")),
+        }
+    }
+    /// Invokes this method on object *`object`* with arguments *`args`* passed as objects. Value type arguments must be boxed, and are unboxed by the runtime.
+    /// Works like `MethodInfo.Invoke` in managed code, so it is useful when arguments are already managed objects(e.g. received through reflection).
+    /// # Example
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// # let domain = Domain::get_current().unwrap();
+    /// # let method:Method<(i32,i32)> = todo!();
+    /// let args = [Object::box_val(&domain,1_i32),Object::box_val(&domain,2_i32)];
+    /// let res = method.invoke_with_object_array(None,&args).expect("Got an exception!");
+    /// ```
+    /// # Errors
+    /// Returns an exception if it was thrown by managed code. Returns `System.Reflection.TargetParameterCountException` if number of *args* does not match number of parameters of this method,
+    /// and `System.ArgumentException` if an argument can't be assigned to its parameter. The runtime does not check arguments itself, so they are checked before the method is invoked.
+    pub fn invoke_with_object_array(
+        &self,
+        object: Option<&Object>,
+        args: &[Object],
+    ) -> Result<Option<Object>, Exception> {
+        use crate::{Array, Dim1D};
+        let params = self.get_params();
+        if params.len() != args.len() {
+            let corlib = crate::Assembly::assembly_loaded("mscorlib")
+                .expect("Assembly mscorlib not loaded!")
+                .get_image();
+            return Err(Exception::from_name_msg(
+                corlib,
+                "System.Reflection",
+                "TargetParameterCountException",
+                &format!(
+                    "Method {} expects {} arguments, but got {}.",
+                    self.get_name(),
+                    params.len(),
+                    args.len()
+                ),
+            )
+            .expect("Could not create TargetParameterCountException!"));
+        }
+        if let Some(index) = params
+            .iter()
+            .zip(args)
+            .position(|(param, arg)| !param.is_assignable_from(&arg.get_class()))
+        {
+            return Err(Exception::argument_exception(
+                &self.get_param_names()[index],
+                &format!(
+                    "Object of type {} can't be passed as a parameter of type {}.",
+                    args[index].get_class(),
+                    params[index]
+                ),
+            ));
+        }
+        let domain =
+            crate::Domain::get_current().expect("Can't invoke a method before JIT started.");
+        let mut params: Array<Dim1D, Object> = Array::new(&domain, &[args.len()]);
+        for (index, arg) in args.iter().enumerate() {
+            params.set([index], arg.clone());
+        }
+        let obj_ptr = object.map_or(core::ptr::null_mut(), ObjectTrait::get_ptr);
+        let mut except: *mut MonoObject = null_mut();
+        #[cfg(feature = "referenced_objects")]
+        let marker = crate::gc::gc_unsafe_enter();
+        let res = unsafe {
+            let res_ptr = crate::binds::mono_runtime_invoke_array(
+                self.get_ptr(),
+                obj_ptr.cast::<c_void>(),
+                params.get_ptr().cast(),
+                std::ptr::addr_of_mut!(except),
+            );
+            match Exception::from_ptr(except) {
+                Some(except) => Err(except),
+                None => Ok(Object::from_ptr(res_ptr)),
+            }
+        };
+        #[cfg(feature = "referenced_objects")]
+        crate::gc::gc_unsafe_exit(marker);
+        res
+    }
+    /// Invokes this method on object *`object`* with already converted arguments *`args`*, without any marshalling.
+    /// Returns a tuple of the returned object(boxed if the method returns a value type) and the exception object thrown by the method, if any.
+    /// # Arguments
+    /// | Name   | Type   | Description|
+    /// |--------|--------|-------|
+    /// |`self`   | `&Self`|Reference to method to invoke. |
+    /// |`object` | [`Option<&Object>`] |Object to invoke method on. Pass [`None`] if method is static. |
+    /// |`args`   | `&[*mut c_void]`|Pointers to arguments: pointers to values for value types, and [`MonoObject`] pointers for reference types. |
+    /// # Safety
+    /// *args* must contain exactly as many pointers as the method has parameters, each of them valid for the type of the parameter.
+    pub unsafe fn invoke_raw(
+        &self,
+        object: Option<&Object>,
+        args: &[*mut c_void],
+    ) -> (Option<Object>, Option<Object>) {
+        //convert object to invoke on to a pointer.
+        let obj_ptr = object.map_or(core::ptr::null_mut(), ObjectTrait::get_ptr);
+        let mut except: *mut MonoObject = null_mut();
+        let res_ptr = crate::binds::mono_runtime_invoke(
+            self.get_ptr(),
+            obj_ptr.cast::<c_void>(),
+            args.as_ptr().cast_mut(),
+            std::ptr::addr_of_mut!(except),
+        );
+        (Object::from_ptr(res_ptr), Object::from_ptr(except))
+    }
     /// Creates new Method type from a [`*mut MonoMethod`], checks if arguments of [`MonoMethod`] and rust representation of a [`Method`] match and if not, returns [`None`].
     /// Returns [`None`] if pointer is null or if method pointer points to has different signature.
     /// # Arguments
@@ -245,7 +577,9 @@ impl<Args: CompareClasses + TupleToFFIPtrs> Method<Args> {
             args_type: PhantomData,
         };
         let params = res.get_params();
-        if <Args as CompareClasses>::compare(&params) {
+        if <Args as CompareClasses>::compare(&params)
+            && <Args as CompareClasses>::compare_byref(&res.get_params_byref())
+        {
             Some(res)
         } else {
             None
@@ -268,9 +602,161 @@ impl<Args: CompareClasses + TupleToFFIPtrs> Method<Args> {
             args_type: PhantomData,
         };
         let params = res.get_params();
-        if !<Args as CompareClasses>::compare(&params) {
+        if !<Args as CompareClasses>::compare(&params)
+            || !<Args as CompareClasses>::compare_byref(&res.get_params_byref())
+        {
             return None;
         }
         Some(res)
     }
 }
+// Returns method represented by `System.Reflection.MethodInfo` *method_info*, or [`None`] if it does not accept arguments of type *Args*.
+pub(crate) fn method_from_method_info<Args: TupleToFFIPtrs + CompareClasses>(
+    method_info: &Object,
+) -> Option<Method<Args>> {
+    // `RuntimeMethodHandle` is a struct containing only the `MonoMethod` pointer.
+    let handle = method_info
+        .call("get_MethodHandle", ())
+        .expect("Got an exception while getting handle of a method!")?;
+    #[cfg(feature = "referenced_objects")]
+    let marker = crate::gc::gc_unsafe_enter();
+    let method_ptr =
+        unsafe { *crate::binds::mono_object_unbox(handle.get_ptr()).cast::<*mut MonoMethod>() };
+    #[cfg(feature = "referenced_objects")]
+    crate::gc::gc_unsafe_exit(marker);
+    unsafe { Method::from_ptr(method_ptr) }
+}
+/// Header of a managed method, describing its IL body. Created by [`Method::get_header`].
+pub struct MethodHeader {
+    header: *mut MonoMethodHeader,
+}
+impl MethodHeader {
+    /// Returns internal pointer to [`MonoMethodHeader`].
+    #[must_use]
+    pub fn get_ptr(&self) -> *mut MonoMethodHeader {
+        self.header
+    }
+    // Returns size of IL code of the method and its max stack depth.
+    fn get_code_info(&self) -> (u32, u32) {
+        let mut code_size = 0;
+        let mut max_stack = 0;
+        unsafe {
+            crate::binds::mono_method_header_get_code(
+                self.header,
+                std::ptr::addr_of_mut!(code_size),
+                std::ptr::addr_of_mut!(max_stack),
+            )
+        };
+        (code_size, max_stack)
+    }
+    /// Returns size of IL code of the method, in bytes.
+    #[must_use]
+    pub fn code_size(&self) -> u32 {
+        self.get_code_info().0
+    }
+    /// Returns maximum depth of the evaluation stack the method uses.
+    #[must_use]
+    pub fn max_stack(&self) -> u32 {
+        self.get_code_info().1
+    }
+    /// Returns number of local variables declared by the method.
+    #[must_use]
+    pub fn num_locals(&self) -> u32 {
+        let mut num_locals = 0;
+        let mut init_locals = 0;
+        unsafe {
+            crate::binds::mono_method_header_get_locals(
+                self.header,
+                std::ptr::addr_of_mut!(num_locals),
+                std::ptr::addr_of_mut!(init_locals),
+            )
+        };
+        num_locals
+    }
+}
+impl Drop for MethodHeader {
+    fn drop(&mut self) {
+        unsafe { crate::binds::mono_metadata_free_mh(self.header) };
+    }
+}
+/// Argument passed by reference(to a `ref` or `out` parameter) to a method called with [`Method::invoke`].
+/// The value managed code assigns to the parameter is written back into the borrowed variable when the call finishes.
+///
+/// The argument is passed as a pointer to a copy of the variable in the layout managed code expects(e.g. `Nullable<T>` for an `Option<i32>`, or a pointer to the object for reference types),
+/// which is converted back after the call. Value types are **not** boxed.
+/// If managed code may assign `null` to a reference type parameter, borrow an [`Option`](e.g. `Option<Object>`), since converting `null` to a non-nullable type panics.
+/// # Example
+/// ## C#
+///```csharp
+/// class SomeClass{
+///     public static bool TryParseInt(string text, out int value){
+///         return int.TryParse(text, out value);
+///     }
+/// }
+///```
+/// ## Rust
+///```no_run
+/// # use wrapped_mono::*;
+/// # fn parse(some_class:Class){
+/// let try_parse:Method<(String,InvokeArgRef<i32>)> = Method::get_from_name(&some_class,"TryParseInt",2).expect("Could not find method!");
+/// let mut value = 0;
+/// let res = try_parse.invoke(None,("42".to_owned(),InvokeArgRef::new(&mut value))).expect("Got an exception!").expect("Got null!");
+/// assert!(res.unbox::<bool>());
+/// assert_eq!(value,42);
+/// # }
+///```
+pub struct InvokeArgRef<'a, T: InteropSend + InteropReceive + InteropClass> {
+    target: &'a mut T,
+    // Managed representation of the argument, which managed code may assign to. [`None`] if the argument was not passed to managed code.
+    value: Option<MaybeUninit<T::SourceType>>,
+}
+impl<'a, T: InteropSend + InteropReceive + InteropClass> InvokeArgRef<'a, T> {
+    /// Creates an argument passing *target* by reference.
+    /// # Panics
+    /// Panics if *T* is a reference type whose managed representation is not a pointer to the object.
+    #[must_use]
+    pub fn new(target: &'a mut T) -> Self {
+        assert!(
+            !T::is_class_type()
+                || std::mem::size_of::<T::SourceType>() == std::mem::size_of::<*mut c_void>(),
+            "Reference type can't be passed by reference, because it is not represented by a pointer to the object!"
+        );
+        Self {
+            target,
+            value: None,
+        }
+    }
+}
+impl<T: InteropSend + InteropReceive + InteropClass> InteropClass for InvokeArgRef<'_, T> {
+    fn get_mono_class() -> Class {
+        T::get_mono_class()
+    }
+    fn is_passed_by_ref() -> bool {
+        true
+    }
+}
+unsafe impl<T: InteropSend + InteropReceive + InteropClass> InteropSend for InvokeArgRef<'_, T> {
+    fn get_ffi_ptr(&mut self) -> *mut c_void {
+        let value = self.value.insert(MaybeUninit::uninit());
+        if T::is_class_type() {
+            // Reference types are stored as a pointer to the object, which `new` checked to be of the right size.
+            unsafe {
+                value
+                    .as_mut_ptr()
+                    .cast::<*mut c_void>()
+                    .write(self.target.get_ffi_ptr());
+            };
+        } else {
+            // Value types are plain data, so a copy of the target can be consumed while the target itself stays in place.
+            unsafe { std::ptr::read(self.target).write_to_mono(value.as_mut_ptr().cast()) };
+        }
+        value.as_mut_ptr().cast()
+    }
+}
+impl<T: InteropSend + InteropReceive + InteropClass> Drop for InvokeArgRef<'_, T> {
+    fn drop(&mut self) {
+        if let Some(value) = self.value.take() {
+            *self.target = T::get_rust_rep(unsafe { value.assume_init() });
+        }
+    }
+}
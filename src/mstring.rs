@@ -7,7 +7,8 @@ use crate::interop::InteropClass;
 use crate::object::Object;
 use crate::Class;
 use crate::ObjectTrait;
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
+use std::hash::{Hash, Hasher};
 #[warn(unused_imports)]
 /// Representation of [`Object`] of type **System.String**.
 pub struct MString {
@@ -32,6 +33,20 @@ impl MString {
         drop(cstr);
         res
     }
+    ///Returns the canonical interned instance of a string with content of *self*, interning *self* if no such string was interned yet.
+    ///Interned strings with the same content are the same **object**, and are never freed during lifetime of the runtime.
+    #[must_use]
+    pub fn intern(&self) -> Self {
+        #[cfg(feature = "referenced_objects")]
+        let marker = gc_unsafe_enter();
+        let res = unsafe {
+            Self::from_ptr(crate::binds::mono_string_intern(self.get_ptr().cast()).cast())
+        }
+        .expect("Could not intern a string!");
+        #[cfg(feature = "referenced_objects")]
+        gc_unsafe_exit(marker);
+        res
+    }
     ///Compares two managed strings. Returns true if their **content** is equal, not if they are the same **object**.
     #[must_use]
     pub fn is_equal(&self, other: &Self) -> bool {
@@ -44,6 +59,23 @@ impl MString {
         gc_unsafe_exit(marker);
         equ
     }
+    ///Returns length of this string, in UTF-16 code units(same as `String.Length` in managed code).
+    #[must_use]
+    pub fn len(&self) -> usize {
+        #[cfg(feature = "referenced_objects")]
+        let marker = gc_unsafe_enter();
+        let len = unsafe { crate::binds::mono_string_length(self.get_ptr().cast()) };
+        #[cfg(feature = "referenced_objects")]
+        gc_unsafe_exit(marker);
+        #[allow(clippy::cast_sign_loss)]
+        let len = len as usize;
+        len
+    }
+    ///Checks if this string is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
     ///Creates hash of a [`String`].
     #[must_use]
     pub fn hash(&self) -> u32 {
@@ -65,18 +97,22 @@ impl ToString for MString {
     fn to_string(&self) -> String {
         #[cfg(feature = "referenced_objects")]
         let marker = gc_unsafe_enter();
-        let cstr = unsafe {
-            CString::from_raw(crate::binds::mono_string_to_utf8(
-                self.get_ptr().cast::<MonoString>(),
-            ))
-        };
-        let res = cstr.to_str().expect("Could not create String!").to_owned();
-        unsafe { crate::binds::mono_free(cstr.into_raw().cast::<std::os::raw::c_void>()) };
+        let ptr = unsafe { crate::binds::mono_string_to_utf8(self.get_ptr().cast::<MonoString>()) };
+        let res = unsafe { CStr::from_ptr(ptr) }
+            .to_str()
+            .expect("Could not create String!")
+            .to_owned();
+        unsafe { crate::binds::mono_free(ptr.cast::<std::os::raw::c_void>()) };
         #[cfg(feature = "referenced_objects")]
         gc_unsafe_exit(marker);
         res
     }
 }
+impl From<&MString> for String {
+    fn from(mstring: &MString) -> Self {
+        mstring.to_string()
+    }
+}
 use crate::binds::MonoObject;
 use crate::Exception;
 impl ObjectTrait for MString {
@@ -115,8 +151,18 @@ impl Clone for MString {
         unsafe { Self::from_ptr(self.get_ptr()).unwrap() } //If object exists then it can't be null
     }
 }
+/// Compares **content** of strings: [`MString`] is equal to *other* if *other* is a string with the same content, even if it is a different object.
 impl<O: ObjectTrait> PartialEq<O> for MString {
     fn eq(&self, other: &O) -> bool {
-        self.get_ptr().cast() == other.get_ptr()
+        other
+            .cast::<Self>()
+            .is_some_and(|other| self.is_equal(&other))
+    }
+}
+impl Eq for MString {}
+/// Hashes **content** of the string, consistently with [`PartialEq`] implementation.
+impl Hash for MString {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u32(Self::hash(self));
     }
 }
@@ -0,0 +1,96 @@
+use crate::binds::{MonoType, MonoTypeNameFormat};
+use crate::Class;
+use std::ffi::CStr;
+/// Safe representation of [`MonoType`], unmanaged description of a type. Unlike [`Class`], it can also describe byref and pointer types.
+#[derive(Copy, Clone)]
+pub struct Type {
+    type_ptr: *mut MonoType,
+}
+impl Type {
+    /// Returns copy of internal pointer representing [`MonoType`].
+    #[must_use]
+    pub fn get_ptr(&self) -> *mut MonoType {
+        self.type_ptr
+    }
+    /// Creates [`Type`] from *`type_ptr`*. If it is not null, returns [`Some`], otherwise [`None`].
+    /// # Safety
+    /// *`type_ptr`* must me either a valid pointer to [`MonoType`] or null pointer.
+    #[must_use]
+    pub unsafe fn from_ptr(type_ptr: *mut MonoType) -> Option<Self> {
+        if type_ptr.is_null() {
+            return None;
+        }
+        Some(Self { type_ptr })
+    }
+    /// Returns class this type represents, if there is one.
+    #[must_use]
+    pub fn get_class(&self) -> Option<Class> {
+        unsafe { Class::from_ptr(crate::binds::mono_class_from_mono_type(self.type_ptr)) }
+    }
+    /// Returns full name of this type(e.g. `System.String`).
+    #[must_use]
+    pub fn get_name(&self) -> String {
+        let ptr = unsafe { crate::binds::mono_type_get_name(self.type_ptr) };
+        let res = unsafe { CStr::from_ptr(ptr) }
+            .to_str()
+            .expect(crate::CSTR2STR_ERR)
+            .to_owned();
+        unsafe { crate::binds::mono_free(ptr.cast()) };
+        res
+    }
+    /// Returns name of this type, formatted according to *format*.
+    #[must_use]
+    pub fn get_name_full(&self, format: TypeNameFormat) -> String {
+        let ptr = unsafe { crate::binds::mono_type_get_name_full(self.type_ptr, format.to_raw()) };
+        let res = unsafe { CStr::from_ptr(ptr) }
+            .to_str()
+            .expect(crate::CSTR2STR_ERR)
+            .to_owned();
+        unsafe { crate::binds::mono_free(ptr.cast()) };
+        res
+    }
+    /// Checks if this type is passed by reference(`ref`, `out` and `in` parameters).
+    #[must_use]
+    pub fn is_byref(&self) -> bool {
+        unsafe { crate::binds::mono_type_is_byref(self.type_ptr) != 0 }
+    }
+    /// Checks if this type is an unmanaged pointer(e.g. `int*`) or function pointer type. Reference types(e.g. `string`) are not pointer types.
+    #[must_use]
+    pub fn is_pointer(&self) -> bool {
+        // `mono_type_is_pointer` also returns true for reference types and native integers, so the kind of the type is checked instead.
+        let kind = unsafe { crate::binds::mono_type_get_type(self.type_ptr) };
+        u32::try_from(kind).is_ok_and(|kind| {
+            kind == crate::binds::MonoTypeEnum_MONO_TYPE_PTR
+                || kind == crate::binds::MonoTypeEnum_MONO_TYPE_FNPTR
+        })
+    }
+}
+impl std::fmt::Debug for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Type{{name:\"{}\"}}", self.get_name())
+    }
+}
+/// Format of a type name returned by [`Type::get_name_full`] and [`Class::full_name`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeNameFormat {
+    /// Name as used in IL(e.g. `System.Collections.Generic.List<System.Int32>`).
+    IL,
+    /// Name as returned by `Type.ToString`(e.g. `System.Collections.Generic.List`1[System.Int32]`).
+    Reflection,
+    /// Name as returned by `Type.FullName`, with generic arguments qualified by their assemblies.
+    FullName,
+    /// Name as returned by `Type.AssemblyQualifiedName`, accepted by `Type.GetType`(e.g. `SomeClass, SomeAssembly, Version=1.0.0.0, Culture=neutral, PublicKeyToken=null`).
+    AssemblyQualified,
+}
+impl TypeNameFormat {
+    fn to_raw(self) -> MonoTypeNameFormat {
+        match self {
+            Self::IL => crate::binds::MonoTypeNameFormat_MONO_TYPE_NAME_FORMAT_IL,
+            Self::Reflection => crate::binds::MonoTypeNameFormat_MONO_TYPE_NAME_FORMAT_REFLECTION,
+            Self::FullName => crate::binds::MonoTypeNameFormat_MONO_TYPE_NAME_FORMAT_FULL_NAME,
+            Self::AssemblyQualified => {
+                crate::binds::MonoTypeNameFormat_MONO_TYPE_NAME_FORMAT_ASSEMBLY_QUALIFIED
+            }
+        }
+    }
+}
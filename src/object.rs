@@ -10,6 +10,10 @@ use crate::{class::Class, domain::Domain, method::Method};
 /// (e.g. when added as an argument to function exposed as an internal call).
 /// It means that while it may represent a nullable type, wrapped-mono will automatically panic if it receives a null value.
 /// For nullable support use `Option<Object>`.
+///
+/// Without the `referenced_objects` feature, an [`Object`] is only a pointer, which the garbage collector does not know about.
+/// It is only valid for the duration of the current internal call(or until the next collection), since the object may be collected or moved.
+/// To store an object for longer(e.g. in a Rust struct), use [`Object::rooted`].
 pub struct Object {
     #[cfg(not(feature = "referenced_objects"))]
     obj_ptr: *mut MonoObject,
@@ -55,7 +59,7 @@ pub trait ObjectTrait: Sized + InteropClass {
     /// # let class = Class::get_int_32();
     /// # let domain = Domain::get_current().unwrap();
     /// let object = Object::new(&domain,&class);
-    /// let object_copy = object.clone_managed_object();
+    /// let object_copy = object.deep_clone();
     /// assert_ne!(object.hash(), object_copy.hash()); // Objects object and object_copy have exactly
     /// // the same values of their fields, but are different instances, so their hash is different.
     /// ```
@@ -68,7 +72,8 @@ pub trait ObjectTrait: Sized + InteropClass {
         gc_unsafe_exit(marker);
         hsh
     }
-    /// get [`Domain`] this object exists in.
+    /// get [`Domain`] this object exists in(the domain it was allocated in).
+    /// Useful when new managed objects(e.g. strings) must be created in the same domain as an object received from managed code.
     /// # Example
     ///```no_run
     /// # use wrapped_mono::*;
@@ -88,6 +93,8 @@ pub trait ObjectTrait: Sized + InteropClass {
         dom
     }
     /// get size of managed object referenced by *self* in bytes. Does include builtin hidden data.
+    /// For arrays and strings the size includes storage of their elements, so it grows with their length.
+    /// Note that [`crate::Array::get_size`] returns only the size of the elements, without the object header.
     /// # Example
     ///```ignore
     /// class SomeClass{};
@@ -167,6 +174,43 @@ pub trait ObjectTrait: Sized + InteropClass {
         gc_unsafe_exit(marker);
         exc.map_or_else(|| Ok(res), Err)
     }
+    /// Reads delegate stored in field *`field_name`* of this object and invokes it with *args*.
+    /// # Example
+    /// ## C#
+    ///```csharp
+    /// class SomeClass{
+    ///     public System.Action<int> callback;
+    /// }
+    ///```
+    /// ## Rust
+    ///```no_run
+    /// # use wrapped_mono::*;
+    /// # fn fire(instance_of_some_class:&Object){
+    /// instance_of_some_class.invoke_delegate_field("callback",&(42,)).expect("Callback threw an exception!");
+    /// # }
+    /// ```
+    /// # Errors
+    /// Returns [`Exception`] thrown by the delegate. If the field does not exist, `MissingFieldException` is returned.
+    /// If the field is null, `NullReferenceException` is returned, and if the delegate can't be invoked with arguments of type *Args*, `MissingMethodException` is returned.
+    fn invoke_delegate_field<Args: TupleToFFIPtrs + CompareClasses + Clone>(
+        &self,
+        field_name: &str,
+        args: &Args,
+    ) -> Result<Option<Object>, Exception> {
+        let class = self.get_class();
+        let field = class
+            .get_field_from_name(field_name)
+            .ok_or_else(|| Exception::missing_field(&class.get_name(), field_name))?;
+        let obj = unsafe { Object::from_ptr(self.get_ptr()) }
+            .expect("Could not convert a non-null object to Object!");
+        let delegate = field
+            .get_value_object(&obj)
+            .ok_or_else(Exception::null_reference)?;
+        let delegate_class = delegate.get_class();
+        let invoke: Method<Args> = Method::get_from_name(&delegate_class, "Invoke", -1)
+            .ok_or_else(|| Exception::missing_method(&delegate_class.get_name(), "Invoke"))?;
+        invoke.invoke(Some(delegate), args.clone())
+    }
 }
 use crate::exception::Exception;
 impl ObjectTrait for Object {
@@ -290,6 +334,107 @@ impl Object {
         gc_unsafe_exit(marker);
         res
     }
+    /// Copies boxed struct out of *self*. Intended for structs deriving [`crate::MonoBlittable`], which have the same layout as their managed counterparts.
+    /// Unlike [`Self::unbox`], which requires the managed type to match exactly, this only checks that the value has the same size as *T*, so mismatched layouts are reported instead of producing garbage.
+    /// # Example
+    /// ## C#
+    ///```csharp
+    /// [StructLayout(LayoutKind.Sequential)]
+    /// struct Vec3{
+    ///     public float x;
+    ///     public float y;
+    ///     public float z;
+    /// }
+    ///```
+    /// ## Rust
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// #[derive(MonoBlittable, Clone, Copy)]
+    /// #[repr(C)]
+    /// struct Vec3{
+    ///     x:f32,
+    ///     y:f32,
+    ///     z:f32,
+    /// }
+    /// #[invokable]
+    /// fn length(boxed:Object)->f32{
+    ///     let vec:Vec3 = boxed.unbox_struct().expect("Not a Vec3!");
+    ///     (vec.x * vec.x + vec.y * vec.y + vec.z * vec.z).sqrt()
+    /// }
+    /// ```
+    /// # Errors
+    /// Returns error message if *self* is not a boxed value type, or its size is different from size of *T*.
+    pub fn unbox_struct<T: InteropReceive<SourceType = T> + Copy>(&self) -> Result<T, String> {
+        let class = self.get_class();
+        if !class.is_valuetype() {
+            return Err(format!(
+                "tried to unbox object of type `{}`, which is not a value type",
+                class.get_name()
+            ));
+        }
+        #[allow(clippy::cast_sign_loss)]
+        let size = class.value_size().0 as usize;
+        if size != std::mem::size_of::<T>() {
+            return Err(format!(
+                "size of type `{}`({size} bytes) does not match size of the target type({} bytes)",
+                class.get_name(),
+                std::mem::size_of::<T>()
+            ));
+        }
+        #[cfg(feature = "referenced_objects")]
+        let marker = gc_unsafe_enter();
+        // Boxed values are not guaranteed to be aligned for *T*.
+        let res = unsafe {
+            crate::binds::mono_object_unbox(self.get_ptr())
+                .cast::<T>()
+                .read_unaligned()
+        };
+        #[cfg(feature = "referenced_objects")]
+        gc_unsafe_exit(marker);
+        Ok(res)
+    }
+    /// Reads the underlying numeric value of a boxed enum. *T* must be the underlying type of the enum(e.g. `i32` for `enum Color : int`).
+    /// # Panics
+    /// Panics if *self* is not a boxed enum, or if *T* is not the underlying type of the enum. Type check is skipped if `unsafe_boxing` feature is enabled.
+    /// # Example
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// #[invokable]
+    /// fn rust_function(color:Object){
+    ///     let val = color.enum_value::<i32>();
+    /// }
+    /// ```
+    #[must_use]
+    pub fn enum_value<T: InteropBox + Copy>(&self) -> T {
+        let class = self.get_class();
+        assert!(
+            class.is_enum(),
+            "tried to read enum value of an object of type `{}`, which is not an enum",
+            class.get_name()
+        );
+        #[cfg(not(feature = "unsafe_boxing"))]
+        {
+            let underlying_class = class.get_enum_underlying_class();
+            let t_class = <T as InteropClass>::get_mono_class();
+            assert!(
+                underlying_class == t_class,
+                "tried to read enum `{}` with underlying type `{}` as type `{}`",
+                &class.get_name(),
+                &underlying_class.get_name(),
+                &t_class.get_name()
+            );
+        }
+        #[cfg(feature = "referenced_objects")]
+        let marker = gc_unsafe_enter();
+        let ptr = unsafe {
+            crate::binds::mono_object_unbox(self.get_ptr())
+                .cast::<<T as InteropReceive>::SourceType>()
+        };
+        let res = T::get_rust_rep(unsafe { *ptr });
+        #[cfg(feature = "referenced_objects")]
+        gc_unsafe_exit(marker);
+        res
+    }
     unsafe fn box_val_unsafe(
         domain: &crate::domain::Domain,
         class: &Class,
@@ -358,8 +503,11 @@ impl Object {
 impl Object {
     /// Clones the underlying [`MonoObject`] *not* the reference to this object. (
     /// e.g. when called on a reference to a managed object A will create second object B, not another reference to object A).
+    /// This is a shallow copy on the managed side: fields referencing other objects will point to the same objects in both copies.
+    ///
+    /// Use [`Clone::clone`] to create another reference to the same managed object instead.
     #[must_use]
-    pub fn clone_managed_object(&self) -> Self {
+    pub fn deep_clone(&self) -> Self {
         //if clone fails, it means that there is a much bigger problem somewhere down the line, so it can be just ignored.
         #[cfg(feature = "referenced_objects")]
         let marker = gc_unsafe_enter();
@@ -369,6 +517,118 @@ impl Object {
         gc_unsafe_exit(marker);
         res
     }
+    /// Checks if this object is an instance of *class*(or a class derived from it, or implementing it if *class* is an interface).
+    /// Returns [`Some`] with the same object if it is, and [`None`] otherwise.
+    /// # Example
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// #[invokable]
+    /// fn print_if_string(obj: Object) {
+    ///     if let Some(string) = obj.isinst(&Class::get_string()) {
+    ///         let string: MString = string.cast().unwrap();
+    ///         println!("{}", string.to_string());
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn isinst(&self, class: &Class) -> Option<Self> {
+        #[cfg(feature = "referenced_objects")]
+        let marker = gc_unsafe_enter();
+        let res = unsafe {
+            Self::from_ptr(crate::binds::mono_object_isinst(
+                self.get_ptr(),
+                class.get_ptr(),
+            ))
+        };
+        #[cfg(feature = "referenced_objects")]
+        gc_unsafe_exit(marker);
+        res
+    }
+    /// Calls method *name* on this object, passing *args* to it. Looks for a method accepting *args* declared in the class of this object or any of its parents,
+    /// so the most derived override of a virtual method is called.
+    /// # Example
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// # let object:Object = todo!();
+    /// let res = object.call("Add", (1, 2)).expect("Got an exception!");
+    /// ```
+    /// # Errors
+    /// Returns an exception if it was thrown by the method, or `System.MissingMethodException` if there is no method *name* accepting *args*.
+    pub fn call<Args: TupleToFFIPtrs + CompareClasses>(
+        &self,
+        name: &str,
+        args: Args,
+    ) -> Result<Option<Self>, Exception> {
+        let object_class = self.get_class();
+        let mut class = Some(object_class);
+        let method = loop {
+            let current =
+                class.ok_or_else(|| Exception::missing_method(&object_class.get_name(), name))?;
+            if let Some(method) = Method::<Args>::find_overload(&current, name) {
+                break method;
+            }
+            class = current.get_parent();
+        };
+        method.invoke(Some(self.clone()), args)
+    }
+    /// Reads value of instance field *name* of this object. Returns [`None`] if there is no such field, or it is not of type *T*.
+    /// Shorthand for getting the class of this object, looking up the field and reading its value with [`crate::ClassField::get_value`].
+    /// # Example
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// # let object:Object = todo!();
+    /// let count = object.get_field::<i32>("count").expect("Could not read field count!");
+    /// ```
+    #[must_use]
+    pub fn get_field<T: InteropBox + Copy>(&self, name: &str) -> Option<T> {
+        let field = self.get_instance_field(name)?;
+        field.get_value(self).ok()
+    }
+    /// Sets value of instance field *name* of this object to *value*. Returns false if there is no such field, or it is not of type *T*.
+    pub fn set_field<T: InteropBox + Copy>(&self, name: &str, value: T) -> bool {
+        self.get_instance_field(name)
+            .is_some_and(|field| field.set_value(self, value).is_ok())
+    }
+    /// Stores reference to *value* in instance field *name* of this object, using the GC write barrier. Returns false if there is no such field, or it can't hold *value*.
+    /// See [`crate::ClassField::set_value_ref`].
+    pub fn set_field_ref(&self, name: &str, value: &Self) -> bool {
+        self.get_instance_field(name)
+            .is_some_and(|field| field.set_value_ref(self, value).is_ok())
+    }
+    /// Reads reference stored in instance field *name* of this object. Returns [`None`] if there is no such field, it holds null or a value type.
+    /// See [`crate::ClassField::get_reference_value`].
+    #[must_use]
+    pub fn get_field_object(&self, name: &str) -> Option<Self> {
+        self.get_instance_field(name)?.get_reference_value(self)
+    }
+    // Looks up non-static field *name* in class of this object.
+    fn get_instance_field(&self, name: &str) -> Option<crate::ClassField> {
+        self.get_class()
+            .get_field_from_name(name)
+            .filter(|field| !field.is_static())
+    }
+    /// Converts this object to a [`RootedObject`], which keeps it alive using a garbage collector handle, so it can be stored for longer than the current internal call.
+    /// # Example
+    /// ```no_run
+    /// # use wrapped_mono::*;
+    /// struct Plugin{
+    ///     instance:RootedObject,
+    /// }
+    /// # let instance:Object = todo!();
+    /// let plugin = Plugin{instance:instance.rooted()};
+    /// ```
+    #[must_use]
+    pub fn rooted(self) -> RootedObject {
+        RootedObject {
+            handle: ManuallyDrop::new(unsafe { GCHandle::create_default(self.get_ptr()) }),
+        }
+    }
+    /// Clones the underlying [`MonoObject`]. Same as [`Self::deep_clone`].
+    #[must_use]
+    #[deprecated(note = "use `Object::deep_clone` instead")]
+    pub fn clone_managed_object(&self) -> Self {
+        self.deep_clone()
+    }
 }
 //for 0.2 TODO:extend functionalities relating to properties.
 use crate::interop::InteropClass;
@@ -382,13 +642,75 @@ impl InteropClass for Option<Object> {
         Class::get_object()
     }
 }
+/// Compares objects by **reference**: two [`Object`]s are equal only if they refer to the same managed object.
+/// Distinct objects with equal values(e.g. two boxed ints holding 5) are *not* equal. To compare values, call the managed `Equals` method.
 impl<O: ObjectTrait> PartialEq<O> for Object {
     fn eq(&self, other: &O) -> bool {
         self.get_ptr() == other.get_ptr()
     }
 }
+impl Eq for Object {}
+/// Hashes **identity** of the managed object, consistently with [`PartialEq`] implementation. Hash stays the same even if the object is moved by the garbage collector.
+impl std::hash::Hash for Object {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_i32(ObjectTrait::hash(self));
+    }
+}
+/// Clones the reference to a managed object, not the object itself. Both references point to the same managed object,
+/// so changes made trough one of them are visible trough the other. Use [`Object::deep_clone`] to create a copy of the managed object.
 impl Clone for Object {
     fn clone(&self) -> Self {
         unsafe { Self::from_ptr(self.get_ptr()).unwrap() } //If object exists then it can't be null
     }
 }
+use std::mem::ManuallyDrop;
+/// Managed object kept alive by a garbage collector handle, created by [`Object::rooted`]. The handle is freed when [`RootedObject`] is dropped.
+///
+/// The handle is not pinned, so the garbage collector may still move the object. Because of that, a [`RootedObject`] does not store a pointer to the object,
+/// and looks the current one up each time it is used. It implements [`ObjectTrait`], and [`Self::get`] returns an [`Object`] for the duration of the current call.
+pub struct RootedObject {
+    handle: ManuallyDrop<GCHandle>,
+}
+impl RootedObject {
+    /// Returns [`Object`] referring to the rooted object. Like any [`Object`], it should not be stored for longer than the current call.
+    #[must_use]
+    pub fn get(&self) -> Object {
+        unsafe { Object::from_ptr_unchecked(self.handle.get_target()) }
+    }
+}
+impl ObjectTrait for RootedObject {
+    fn get_ptr(&self) -> *mut MonoObject {
+        self.handle.get_target()
+    }
+    unsafe fn from_ptr_unchecked(obj_ptr: *mut MonoObject) -> Self {
+        Self {
+            handle: ManuallyDrop::new(GCHandle::create_default(obj_ptr)),
+        }
+    }
+}
+impl InteropClass for RootedObject {
+    fn get_mono_class() -> Class {
+        Class::get_object()
+    }
+}
+/// Creates a new handle to the same managed object.
+impl Clone for RootedObject {
+    fn clone(&self) -> Self {
+        unsafe { Self::from_ptr_unchecked(self.get_ptr()) }
+    }
+}
+impl<O: ObjectTrait> PartialEq<O> for RootedObject {
+    fn eq(&self, other: &O) -> bool {
+        self.get_ptr() == other.get_ptr()
+    }
+}
+impl Drop for RootedObject {
+    fn drop(&mut self) {
+        let handle = unsafe { ManuallyDrop::take(&mut self.handle) };
+        // With `referenced_objects`, the handle frees itself when dropped.
+        #[cfg(not(feature = "referenced_objects"))]
+        GCHandle::free(handle);
+        #[cfg(feature = "referenced_objects")]
+        drop(handle);
+    }
+}
@@ -66,6 +66,30 @@ impl ReflectionType {
         res
     }
 }
+// Returns length of the array returned by calling `GetGenericArguments` on reflection object *obj*(a `System.Type` or `System.Reflection.MethodBase`).
+pub(crate) fn generic_arguments_len(obj: &Object) -> u32 {
+    // Look up the most derived override, since `GetGenericArguments` is virtual.
+    let mut class = Some(obj.get_class());
+    let get_args = loop {
+        let current = class.expect("Reflection object has no GetGenericArguments method!");
+        if let Some(method) = Method::<()>::get_from_name(&current, "GetGenericArguments", 0) {
+            break method;
+        }
+        class = current.get_parent();
+    };
+    let args = get_args
+        .invoke(Some(obj.clone()), ())
+        .expect("Got an exception while calling GetGenericArguments!")
+        .expect("GetGenericArguments returned null!");
+    #[cfg(feature = "referenced_objects")]
+    let marker = gc_unsafe_enter();
+    let len = unsafe { crate::binds::mono_array_length(args.get_ptr().cast()) };
+    #[cfg(feature = "referenced_objects")]
+    gc_unsafe_exit(marker);
+    #[allow(clippy::cast_possible_truncation)]
+    let len = len as u32;
+    len
+}
 /*
 impl InteropReceive for ReflectionType {
     type SourceType = *mut MonoReflectionType;
@@ -14,6 +14,22 @@ rusty_fork_test! {
         assert!(arr.len() == 8*16);
     }
     #[test]#[allow(non_snake_case)]
+    fn read_2D_array_from_method(){
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let img = asm.get_image();
+        let class = Class::from_name(&img,"","TestFunctions").expect("Could not get class");
+        let mthd:Method<()> = Method::get_from_name(&class,"GetFilled2DIntArray",0).expect("Could not load function");
+        let arr:Array<Dim2D,i32> = Object::cast(&mthd.invoke(None,()).expect("Exception").expect("got null")).expect("Not Int[,]");
+        assert!(arr.get_lengths() == [3,4]);
+        for i in 0..3{
+            for j in 0..4{
+                let val = arr.get([i,j]);
+                assert!(val == (i * 10 + j) as i32,"{} != {}",val,i * 10 + j);
+            }
+        }
+    }
+    #[test]#[allow(non_snake_case)]
     fn create_4D_array(){
         let dom = jit::init("root",None);
         let arr:Array<Dim4D,i32> = Array::new(&dom,&[1,2,3,4]);
@@ -21,6 +37,65 @@ rusty_fork_test! {
         assert!(arr.len() == 2*3*4);
         assert!(arr.get_class().get_rank() == 4);
     }
+    #[test]
+    fn string_array_set_get(){
+        let dom = jit::init("root",None);
+        let mut arr:Array<Dim1D,MString> = Array::new(&dom,&[3]);
+        for (i,text) in ["a","b","c"].iter().enumerate(){
+            arr.set([i],MString::new(&dom,text));
+        }
+        let strings:Vec<String> = arr.iter().map(|s| s.to_string()).collect();
+        assert_eq!(strings,vec!["a","b","c"]);
+    }
+    #[test]
+    fn array_length_and_size(){
+        let dom = jit::init("root",None);
+        let arr:Array<Dim2D,i64> = Array::new(&dom,&[3,5]);
+        assert_eq!(arr.length(0),3);
+        assert_eq!(arr.length(1),5);
+        assert_eq!(arr.len(),3*5);
+        assert_eq!(Array::<Dim2D,i64>::element_size(),8);
+        assert_eq!(arr.get_size(),3*5*8);
+    }
+    #[test]
+    fn array_copy_from_slice(){
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not get class");
+        let data:Vec<f32> = (0..10_000).map(|i| i as f32 * 0.5).collect();
+        let mut arr:Array<Dim1D,f32> = Array::new(&dom,&[data.len()]);
+        arr.copy_from_slice(&data);
+        let get_at:Method<(Array<Dim1D,f32>,i32)> = Method::get_from_name(&class,"GetFloatAt",2).expect("Could not find method!");
+        for index in [0,1,4_999,9_999]{
+            let res = get_at.invoke(None,(arr.clone(),index)).expect("Got an exception").expect("Got null").unbox::<f32>();
+            assert_eq!(res,data[index as usize]);
+        }
+    }
+    #[test]
+    fn array_copy_to_slice(){
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not get class");
+        let get_squares:Method<(i32,)> = Method::get_from_name(&class,"GetSquaresIntArray",1).expect("Could not find method!");
+        let obj = get_squares.invoke(None,(10_000,)).expect("Got an exception").expect("Got null");
+        let arr:Array<Dim1D,i32> = obj.cast().expect("Expected an int array!");
+        let mut data = vec![0;arr.len()];
+        arr.copy_to_slice(&mut data);
+        for (i,value) in data.iter().enumerate(){
+            assert_eq!(*value,arr.get([i]));
+        }
+        // Copying into a shorter slice copies only the beginning of the array.
+        let mut head = [0;4];
+        arr.copy_to_slice(&mut head);
+        assert_eq!(head,[0,1,4,9]);
+    }
+    #[test]
+    #[should_panic]
+    fn array_copy_from_slice_length_mismatch(){
+        let dom = jit::init("root",None);
+        let mut arr:Array<Dim1D,f32> = Array::new(&dom,&[4]);
+        arr.copy_from_slice(&[1.0,2.0]);
+    }
     #[test]#[allow(non_snake_case)]
     fn create_1D_array(){
         let dom = jit::init("root",None);
@@ -44,6 +119,39 @@ rusty_fork_test! {
         }
     }
     #[test]#[allow(non_snake_case)]
+    fn pass_1D_array_to_method(){
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let img = asm.get_image();
+        let class = Class::from_name(&img,"","TestFunctions").expect("Could not get class");
+        let mthd:Method<(Array<Dim1D,i32>,)> = Method::get_from_name(&class,"SumIntArray",1).expect("Could not load function");
+        let mut arr:Array<Dim1D,i32> = Array::new(&dom,&[4]);
+        for i in 0..4{
+            arr.set([i],(i as i32 + 1) * 10);
+        }
+        let sum = mthd.invoke(None,(arr,)).expect("Exception").expect("got null").unbox::<i32>();
+        assert!(sum == 10 + 20 + 30 + 40);
+    }
+    #[test]#[allow(non_snake_case)]
+    fn iterate_1D_array_from_method(){
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let img = asm.get_image();
+        let class = Class::from_name(&img,"","TestFunctions").expect("Could not get class");
+        let mthd:Method<(i32,)> = Method::get_from_name(&class,"GetSquaresIntArray",1).expect("Could not load function");
+        let arr:Array<Dim1D,i32> = Object::cast(&mthd.invoke(None,(9,)).expect("Exception").expect("got null")).expect("Not Int[]");
+        let vec = arr.to_vec();
+        assert!(vec.len() == 9);
+        let mut i = 0;
+        for val in &arr{
+            assert!(val == vec[i],"{} != {}",val,vec[i]);
+            assert!(val == (i * i) as i32);
+            i += 1;
+        }
+        assert!(i == 9);
+        assert!(arr.into_iter().collect::<Vec<_>>() == vec);
+    }
+    #[test]#[allow(non_snake_case)]
     fn acces_2D_array(){
         let dom = jit::init("root",None);
         let mut arr:Array<Dim2D,usize> = Array::new(&dom,&[89,13]);
@@ -63,6 +171,17 @@ rusty_fork_test! {
             }
         }
     }
+    #[test]#[allow(non_snake_case)]
+    fn try_acces_2D_array_outside_bound(){
+        let dom = jit::init("root",None);
+        let mut arr:Array<Dim2D,i32> = Array::new(&dom,&[4,3]);
+        assert!(arr.try_set([3,2],7).is_ok());
+        assert!(arr.try_get([3,2]) == Ok(7));
+        let err = arr.try_get([1,3]).expect_err("Got a value from outside of array bounds");
+        assert!(err == ArrayIndexError{dimension:1,index:3,length:3});
+        assert!(arr.try_get([4,0]).is_err());
+        assert!(arr.try_set([0,9],1).is_err());
+    }
     #[allow(non_snake_case)]
     #[cfg(not(feature = "unsafe_arrays"))]
     #[test]
@@ -86,4 +205,32 @@ rusty_fork_test! {
             }
         }
     }
+    #[test]
+    fn array_element_class_and_rank(){
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not get class");
+        let get_squares:Method<(i32,)> = Method::get_from_name(&class,"GetSquaresIntArray",1).expect("Could not find method!");
+        let arr:Array<Dim1D,i32> = get_squares.invoke(None,(4,)).expect("Got an exception").expect("Got null").cast().expect("Expected an int array!");
+        assert!(arr.element_class() == Class::get_int_32());
+        assert_eq!(arr.rank(),1);
+        let get_2d:Method<()> = Method::get_from_name(&class,"Get2DIntArray",0).expect("Could not find method!");
+        let arr:Array<Dim2D,i32> = get_2d.invoke(None,()).expect("Got an exception").expect("Got null").cast().expect("Expected a 2D int array!");
+        assert!(arr.element_class() == Class::get_int_32());
+        assert_eq!(arr.rank(),2);
+    }
+    #[test]
+    fn nullable_array_round_trip(){
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not get class");
+        let increment:Method<(Array<Dim1D,Option<i32>>,)> = Method::get_from_name(&class,"IncrementNullableIntArray",1).expect("Could not find method!");
+        let mut arr:Array<Dim1D,Option<i32>> = Array::new(&dom,&[3]);
+        arr.set([0],Some(1));
+        arr.set([1],None);
+        arr.set([2],Some(-8));
+        assert_eq!(arr.to_vec(),vec![Some(1),None,Some(-8)]);
+        let res:Array<Dim1D,Option<i32>> = increment.invoke(None,(arr,)).expect("Got an exception").expect("Got null").cast().expect("Expected an int?[]!");
+        assert_eq!(res.to_vec(),vec![Some(2),None,Some(-7)]);
+    }
 }
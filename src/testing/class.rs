@@ -32,7 +32,14 @@ rusty_fork_test! {
         let img = asm.get_image();
         let test_class = Class::from_name(&img,"","TestFunctions").expect("Could not find class");
         let dbg_fmt = format!("{test_class:?}");
-        assert_eq!(dbg_fmt,"Class{namespace:\"\",name:\"TestFunctions\"}");
+        assert_eq!(dbg_fmt,format!("Class{{namespace:\"\",name:\"TestFunctions\",image:\"{}\"}}",img.get_name()));
+        assert_eq!(format!("{test_class}"),"TestFunctions");
+    }
+    #[test]
+    fn display(){
+        use wrapped_mono::*;
+        let _domain = jit::init("main",None);
+        assert_eq!(format!("{}",Class::get_string()),"System.String");
     }
     #[test]
     fn get_parrent(){
@@ -138,4 +145,414 @@ rusty_fork_test! {
         let _res = met.invoke(None,()).expect("Got an exception").unwrap();
     }
 
+    #[test]
+    fn thread_static_field(){
+        use wrapped_mono::*;
+        use std::sync::Barrier;
+        let domain = jit::init("main",None);
+        let asm = domain.assembly_open("test/dlls/Test.dll").expect("Could not load assembly");
+        let class = Class::from_name(&asm.get_image(),"","ThreadStaticHolder").expect("Could not find class");
+        let barrier = Barrier::new(2);
+        std::thread::scope(|s|{
+            let threads:Vec<_> = [7,13].into_iter().map(|value|{
+                let (domain,barrier) = (&domain,&barrier);
+                s.spawn(move ||{
+                    domain.attach_thread();
+                    let field = class.get_field_from_name("perThread").expect("Could not find field");
+                    class.set_thread_static_field_value(&field,value).expect("Exception in static constructor");
+                    // Make sure both threads have set their values before reading them back.
+                    barrier.wait();
+                    class.get_thread_static_field_value::<i32>(&field).expect("Exception in static constructor")
+                })
+            }).collect();
+            let values:Vec<i32> = threads.into_iter().map(|t| t.join().expect("Thread panicked!")).collect();
+            assert!(values == [7,13],"{:?}",values);
+        });
+        let field = class.get_field_from_name("perThread").expect("Could not find field");
+        assert!(class.get_thread_static_field_value::<i32>(&field).expect("Exception in static constructor") == 0);
+    }
+    #[test]
+    fn class_is_assignable_from(){
+        use wrapped_mono::*;
+        let _domain = jit::init("main",None);
+        let object = Class::get_object();
+        let string = Class::get_string();
+        assert!(object.is_assignable_from(&string));
+        assert!(!string.is_assignable_from(&object));
+        assert!(string.is_assignable_from(&string));
+    }
+    #[test]
+    fn class_get_name_repeated(){
+        use wrapped_mono::*;
+        let domain = jit::init("main",None);
+        let asm = domain.assembly_open("test/dlls/Test.dll").expect("Could not load assembly");
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not find class");
+        let field = class.get_field_from_name("someField").expect("Could not find field");
+        let string = Class::get_string();
+        for _ in 0..100_000{
+            assert!(class.get_name() == "TestFunctions");
+            assert!(string.get_namespace() == "System");
+            assert!(field.get_name() == "someField");
+        }
+    }
+    #[test]
+    fn read_and_write_property(){
+        use wrapped_mono::*;
+        let domain = jit::init("main",None);
+        let asm = domain.assembly_open("test/dlls/Test.dll").expect("Could not load assembly");
+        let class = Class::from_name(&asm.get_image(),"","PropertyHolder").expect("Could not find class");
+        let obj = Object::new(&domain,&class);
+        let ctor:Method<()> = Method::get_from_name(&class,".ctor",0).expect("Could not find constructor");
+        ctor.invoke(Some(obj.clone()),()).expect("Exception in constructor");
+        let property = class.get_property_from_name("Number").expect("Could not find property");
+        assert!(property.get_name() == "Number");
+        let value = property.get_value(Some(&obj)).expect("Exception in getter").expect("Got null").unbox::<i32>();
+        assert!(value == 7,"{} != 7",value);
+        property.set_value(Some(&obj),42_i32).expect("Exception in setter");
+        assert!(property.set_value(Some(&obj),42_i64).is_err());
+        let getter = property.get_get_method().expect("Could not get getter");
+        let value = getter.invoke(Some(obj.clone()),()).expect("Exception in getter").expect("Got null").unbox::<i32>();
+        assert!(value == 42,"{} != 42",value);
+        assert!(property.get_set_method::<i32>().is_some());
+    }
+    #[test]
+    fn enumerate_properties(){
+        use wrapped_mono::*;
+        let domain = jit::init("main",None);
+        let asm = domain.assembly_open("test/dlls/Test.dll").expect("Could not load assembly");
+        let class = Class::from_name(&asm.get_image(),"","PropertyHolder").expect("Could not find class");
+        let names:Vec<String> = class.get_properties().iter().map(ClassProperty::get_name).collect();
+        assert!(names.len() == 2,"{:?}",names);
+        assert!(names.contains(&"Number".to_owned()),"{:?}",names);
+        assert!(names.contains(&"Label".to_owned()),"{:?}",names);
+    }
+    #[test]
+    fn field_flags(){
+        use wrapped_mono::*;
+        let domain = jit::init("main",None);
+        let asm = domain.assembly_open("test/dlls/Test.dll").expect("Could not load assembly");
+        let class = Class::from_name(&asm.get_image(),"","FieldFlagsHolder").expect("Could not find class");
+        let instance_field = class.get_field_from_name("instanceField").expect("Could not find field");
+        assert!(!instance_field.is_static());
+        assert!(instance_field.is_public());
+        assert!(instance_field.get_type() == Class::get_int_32());
+        let static_field = class.get_field_from_name("staticField").expect("Could not find field");
+        assert!(static_field.is_static());
+        assert!(!static_field.is_public());
+        assert!(static_field.get_type() == Class::get_int_64());
+    }
+    #[test]
+    fn static_field_value(){
+        use wrapped_mono::*;
+        let domain = jit::init("main",None);
+        let asm = domain.assembly_open("test/dlls/Test.dll").expect("Could not load assembly");
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not find class");
+        let field = class.get_field_from_name("counter").expect("Could not find field");
+        assert!(field.get_static_value::<i32>(&domain).expect("Exception in static constructor") == 0);
+        field.set_static_value::<i32>(&domain,77).expect("Exception in static constructor");
+        assert!(field.get_static_value::<i32>(&domain).expect("Exception in static constructor") == 77);
+        let get_counter:Method<()> = Method::get_from_name(&class,"GetCounter",0).expect("Could not find method");
+        let value = get_counter.invoke(None,()).expect("Exception").expect("Got null").unbox::<i32>();
+        assert!(value == 77,"{} != 77",value);
+    }
+    #[test]
+    fn class_is_subclass_of(){
+        use wrapped_mono::*;
+        let domain = jit::init("main",None);
+        let asm = domain.assembly_open("test/dlls/Test.dll").expect("Could not load assembly");
+        let img = asm.get_image();
+        let base = Class::from_name(&img,"","BaseClass").expect("Could not find class");
+        let derived = Class::from_name(&img,"","DerivedClass").expect("Could not find class");
+        let iface = Class::from_name(&img,"","IInterfaceOne").expect("Could not find interface");
+        assert!(derived.is_subclass_of(&base,false));
+        assert!(!base.is_subclass_of(&derived,false));
+        assert!(derived.is_subclass_of(&iface,true));
+        assert!(!derived.is_subclass_of(&iface,false));
+    }
+    #[test]
+    fn class_events(){
+        use wrapped_mono::*;
+        let domain = jit::init("main",None);
+        let asm = domain.assembly_open("test/dlls/Test.dll").expect("Could not load assembly");
+        let class = Class::from_name(&asm.get_image(),"","EventHolder").expect("Could not find class");
+        assert!(class.num_events() == 1);
+        let events = class.get_events();
+        assert!(events.len() == 1);
+        assert!(events[0].get_name() == "SomethingHappened");
+        assert!(events[0].get_parent() == class);
+        assert!(events[0].get_add_method::<Object>().is_some());
+        assert!(events[0].get_remove_method::<Object>().is_some());
+    }
+    #[test]
+    fn class_value_size(){
+        use wrapped_mono::*;
+        let _domain = jit::init("main",None);
+        let (size,align) = Class::get_int_32().value_size();
+        assert!(size == 4,"{} != 4",size);
+        assert!(align > 0 && align <= 4,"{}",align);
+        assert!(Class::get_int_64().value_size().0 == 8);
+    }
+    #[test]
+    fn class_instance_size(){
+        use wrapped_mono::*;
+        let domain = jit::init("main",None);
+        let asm = domain.assembly_open("test/dlls/Test.dll").expect("Could not load assembly");
+        let img = asm.get_image();
+        let empty = Class::from_name(&img,"","EmptyClass").expect("Could not find class");
+        let long_holder = Class::from_name(&img,"","LongHolder").expect("Could not find class");
+        assert!(empty.instance_size() >= std::mem::size_of::<binds::MonoObject>() as i32);
+        assert!(long_holder.instance_size() >= empty.instance_size() + 8,"{} < {} + 8",long_holder.instance_size(),empty.instance_size());
+    }
+    #[test]
+    fn string_class_type(){
+        use wrapped_mono::*;
+        let _domain = jit::init("main",None);
+        let string_type = Class::get_string().get_type();
+        assert_eq!(string_type.get_name(),"System.String");
+        assert!(!string_type.is_byref());
+        assert!(!string_type.is_pointer());
+        assert_eq!(string_type.get_class(),Some(Class::get_string()));
+    }
+    #[test]
+    fn class_type_attributes(){
+        use wrapped_mono::*;
+        let domain = jit::init("main",None);
+        let asm = domain.assembly_open("test/dlls/Test.dll").expect("Could not load assembly");
+        let img = asm.get_image();
+        let interface = Class::from_name(&img,"","IInterfaceOne").expect("Could not find class");
+        let abstract_class = Class::from_name(&img,"","AbstractClass").expect("Could not find class");
+        let sealed_class = Class::from_name(&img,"","SealedClass").expect("Could not find class");
+        assert!(interface.is_interface());
+        assert!(interface.is_abstract());
+        assert!(!interface.is_sealed());
+        assert!(!interface.is_public());
+        assert!(!abstract_class.is_interface());
+        assert!(abstract_class.is_abstract());
+        assert!(!abstract_class.is_sealed());
+        assert!(abstract_class.is_public());
+        assert!(!sealed_class.is_interface());
+        assert!(!sealed_class.is_abstract());
+        assert!(sealed_class.is_sealed());
+        assert!(sealed_class.is_public());
+    }
+    #[test]
+    fn get_interfaces(){
+        use wrapped_mono::*;
+        let domain = jit::init("main",None);
+        let asm = domain.assembly_open("test/dlls/Test.dll").expect("Could not load assembly");
+        let class = Class::from_name(&asm.get_image(),"","TwoInterfaces").expect("Could not find class");
+        let mut names:Vec<String> = class.get_interfaces().iter().map(Class::get_name).collect();
+        names.sort();
+        assert_eq!(names,["IInterfaceOne","IInterfaceTwo"]);
+    }
+    #[test]
+    fn class_custom_attributes(){
+        use wrapped_mono::*;
+        let domain = jit::init("main",None);
+        let asm = domain.assembly_open("test/dlls/Test.dll").unwrap();
+        let img = asm.get_image();
+        let plugin = Class::from_name(&img,"","PluginClass").expect("Could not get class");
+        let plugin_attribute = Class::from_name(&img,"","PluginAttribute").expect("Could not get class");
+        assert!(!plugin.get_custom_attributes(None).is_empty());
+        let attrs = plugin.get_custom_attributes(Some(&plugin_attribute));
+        assert_eq!(attrs.len(),1);
+        assert_eq!(attrs[0].get_class(),plugin_attribute);
+        let name:MString = attrs[0].call("get_Name",()).expect("Got an exception").expect("Got null").cast().expect("Name is not a string");
+        assert_eq!(name.to_string(),"test plugin");
+        let empty = Class::from_name(&img,"","EmptyClass").expect("Could not get class");
+        assert!(empty.get_custom_attributes(Some(&plugin_attribute)).is_empty());
+    }
+    #[test]
+    fn enum_underlying_value(){
+        use wrapped_mono::*;
+        let domain = jit::init("main",None);
+        let asm = domain.assembly_open("test/dlls/Test.dll").unwrap();
+        let img = asm.get_image();
+        let color = Class::from_name(&img,"","Color").expect("Could not get class");
+        assert_eq!(color.get_enum_underlying_class(),Class::get_int_32());
+        let small_color = Class::from_name(&img,"","SmallColor").expect("Could not get class");
+        assert_eq!(small_color.get_enum_underlying_class(),Class::get_byte());
+        let provider = Class::from_name(&img,"","EnumProvider").expect("Could not get class");
+        let get_green:Method<()> = Method::get_from_name(&provider,"GetGreen",0).expect("Could not get method");
+        let green = get_green.invoke(None,()).expect("Got an exception").expect("Got null");
+        assert_eq!(green.get_class(),color);
+        assert_eq!(green.enum_value::<i32>(),2);
+        let get_blue:Method<()> = Method::get_from_name(&provider,"GetBlue",0).expect("Could not get method");
+        let blue = get_blue.invoke(None,()).expect("Got an exception").expect("Got null");
+        assert_eq!(blue.enum_value::<u8>(),3);
+    }
+    #[test]
+    #[should_panic]
+    fn enum_value_wrong_type(){
+        use wrapped_mono::*;
+        let domain = jit::init("main",None);
+        let asm = domain.assembly_open("test/dlls/Test.dll").unwrap();
+        let provider = Class::from_name(&asm.get_image(),"","EnumProvider").expect("Could not get class");
+        let get_green:Method<()> = Method::get_from_name(&provider,"GetGreen",0).expect("Could not get method");
+        let green = get_green.invoke(None,()).expect("Got an exception").expect("Got null");
+        // Same size as the underlying `int`, but a different type.
+        let _ = green.enum_value::<u32>();
+    }
+    #[test]
+    fn class_cache(){
+        use wrapped_mono::*;
+        use std::time::Instant;
+        let domain = jit::init("main",None);
+        let asm = domain.assembly_open("test/dlls/Test.dll").unwrap();
+        let img = asm.get_image();
+        let cache = ClassCache::new();
+        let expected = Class::from_name(&img,"","TestFunctions").expect("Could not get class");
+        const LOOKUPS:usize = 10_000;
+        let start = Instant::now();
+        for _ in 0..LOOKUPS{
+            assert_eq!(Class::from_name(&img,"","TestFunctions"),Some(expected));
+        }
+        let uncached = start.elapsed();
+        let start = Instant::now();
+        for _ in 0..LOOKUPS{
+            assert_eq!(cache.from_name(&img,"","TestFunctions"),Some(expected));
+        }
+        let cached = start.elapsed();
+        // Loose bound, so the test does not depend on timing noise.
+        assert!(cached < uncached * 4,"cached {cached:?}, uncached {uncached:?}");
+        assert!(cache.from_name(&img,"","NotAClass").is_none());
+        // Cache can be shared between threads.
+        std::thread::scope(|s|{
+            s.spawn(||{
+                assert_eq!(cache.from_name(&expected.get_image(),"","TestFunctions"),Some(expected));
+            });
+        });
+        cache.clear();
+        assert_eq!(cache.from_name(&img,"","TestFunctions"),Some(expected));
+        // Unloading a domain drops cached classes, which are then looked up again.
+        Domain::create().unload().expect("Could not unload domain");
+        assert_eq!(cache.from_name(&img,"","TestFunctions"),Some(expected));
+    }
+    #[test]
+    fn class_methods_iter(){
+        use wrapped_mono::*;
+        let dom = jit::init("dom",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","MethodIterHolder").expect("Could not get class");
+        let mut methods = class.methods_iter::<(i32,)>();
+        let add = methods.find(|method| method.get_name() == "Add").expect("Could not find method!");
+        assert_eq!(add.get_param_count(),1);
+        // Iteration stopped at `Add`, so the remaining method is still available.
+        let next = methods.next().expect("Iterator did not stop early!");
+        assert_eq!(next.get_name(),"AddTwice");
+        assert!(methods.next().is_none());
+        // Methods with other signatures are skipped.
+        assert!(class.methods_iter::<(i32,)>().all(|method| method.get_name() != "Get"));
+        assert!(class.fields_iter().next().is_none());
+        let holder = Class::from_name(&asm.get_image(),"","PropertyHolder").expect("Could not get class");
+        assert!(holder.properties_iter().any(|prop| prop.get_name() == "Label"));
+    }
+    #[test]
+    fn class_get_fields_named(){
+        use wrapped_mono::*;
+        let dom = jit::init("dom",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let base = Class::from_name(&asm.get_image(),"","ShadowBase").expect("Could not get class");
+        let derived = Class::from_name(&asm.get_image(),"","ShadowDerived").expect("Could not get class");
+        let fields = derived.get_fields_named("value");
+        assert_eq!(fields.len(),2);
+        // Field of the derived class comes first.
+        assert!(fields[0].is_static());
+        assert_eq!(fields[0].get_parent(),derived);
+        assert_eq!(fields[0].get_type(),Class::get_int_64());
+        assert!(!fields[1].is_static());
+        assert_eq!(fields[1].get_parent(),base);
+        assert_eq!(fields[1].get_type(),Class::get_int_32());
+        assert_eq!(base.get_fields_named("value").len(),1);
+        assert!(derived.get_fields_named("missing").is_empty());
+    }
+    #[test]
+    fn class_get_method_by_index(){
+        use wrapped_mono::*;
+        let dom = jit::init("dom",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","MethodIterHolder").expect("Could not get class");
+        let count = class.num_methods() as u32;
+        let by_index:Vec<_> = (0..count).filter_map(|index| class.get_method_by_index::<(i32,)>(index)).map(|method| method.get_ptr()).collect();
+        let iterated:Vec<_> = class.methods_iter::<(i32,)>().map(|method| method.get_ptr()).collect();
+        assert_eq!(by_index.len(),2);
+        assert_eq!(by_index,iterated);
+        assert!(class.get_method_by_index::<()>(count).is_none());
+    }
+    #[test]
+    fn class_vtable_init_runtime(){
+        use wrapped_mono::*;
+        let dom = jit::init("dom",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","StaticCtorHolder").expect("Could not get class");
+        let vtable = class.get_vtable(&dom).expect("Could not get vtable");
+        assert_eq!(vtable.get_class(),class);
+        assert!(vtable.get_domain() == dom);
+        vtable.init_runtime().expect("Static constructor threw an exception");
+        let field = class.get_field_from_name("value").expect("Could not find field");
+        assert_eq!(field.get_static_value::<i32>(&dom).expect("Exception in static constructor"),10);
+        let throwing = Class::from_name(&asm.get_image(),"","ThrowingStaticCtor").expect("Could not get class");
+        let throwing_vtable = throwing.get_vtable(&dom).expect("Could not get vtable");
+        let err = throwing_vtable.init_runtime().expect_err("Static constructor did not throw");
+        assert_eq!(err.class_name(),"System.TypeInitializationException");
+        assert!(err.inner().is_some());
+        // Failed initialization is remembered by the runtime.
+        assert!(throwing_vtable.init_runtime().is_err());
+        let throwing_field = throwing.get_field_from_name("value").expect("Could not find field");
+        let err = throwing_field.get_static_value::<i32>(&dom).expect_err("Static constructor did not throw");
+        assert_eq!(err.class_name(),"System.TypeInitializationException");
+        assert!(throwing_field.set_static_value::<i32>(&dom,1).is_err());
+    }
+    #[test]
+    fn class_and_method_tokens(){
+        use wrapped_mono::*;
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not get class");
+        let token = class.get_type_token();
+        assert_eq!(token & 0xFF00_0000,0x0200_0000,"{:#x}",token);
+        let met:Method<()> = Method::get_from_name(&class,"GetOne",0).expect("Could not get method");
+        let token = met.get_token();
+        assert_eq!(token & 0xFF00_0000,0x0600_0000,"{:#x}",token);
+        assert_ne!(token & 0x00FF_FFFF,0);
+    }
+    #[test]
+    fn class_field_eq_and_offset(){
+        use wrapped_mono::*;
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","LayoutHolder").expect("Could not get class");
+        let first = class.get_field_from_name("first").expect("Could not get field");
+        assert!(first == class.get_field("first").expect("Could not get field"));
+        assert!(first != class.get_field_from_name("second").expect("Could not get field"));
+        let offsets:Vec<u32> = class.get_fields().iter().map(ClassField::get_offset).collect();
+        assert_eq!(offsets.len(),3);
+        assert!(offsets.windows(2).all(|pair| pair[0] < pair[1]),"{:?}",offsets);
+    }
+    #[test]
+    fn class_full_name(){
+        use wrapped_mono::*;
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not get class");
+        assert_eq!(class.full_name(TypeNameFormat::IL),"TestFunctions");
+        assert_eq!(class.full_name(TypeNameFormat::Reflection),"TestFunctions");
+        let qualified = class.full_name(TypeNameFormat::AssemblyQualified);
+        assert!(qualified.starts_with("TestFunctions, Test"),"{}",qualified);
+        let string = Class::get_string();
+        assert!(string.full_name(TypeNameFormat::AssemblyQualified).starts_with("System.String, mscorlib"));
+    }
+    #[test]
+    fn class_try_from_name(){
+        use wrapped_mono::*;
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let img = asm.get_image();
+        assert!(Class::try_from_name(&img,"","TestFunctions").is_ok());
+        assert_eq!(Class::try_from_name(&img,"","Missing").err(),Some(ClassLookupError::NotFound));
+        // Name must not be truncated at the null byte, which would find `TestFunctions`.
+        assert_eq!(Class::try_from_name(&img,"","TestFunctions\0Suffix").err(),Some(ClassLookupError::InteriorNul));
+        assert_eq!(Class::try_from_name(&img,"\0","TestFunctions").err(),Some(ClassLookupError::InteriorNul));
+        assert!(Class::from_name(&img,"","TestFunctions\0Suffix").is_none());
+    }
 }
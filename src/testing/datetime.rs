@@ -0,0 +1,30 @@
+use crate as wrapped_mono;
+use rusty_fork::rusty_fork_test;
+use std::time::{Duration, SystemTime};
+use wrapped_mono::*;
+rusty_fork_test! {
+    #[test]
+    fn datetime_unix_epoch(){
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","DateTimeProvider").expect("Could not get class");
+        let met:Method<()> = Method::get_from_name(&class,"GetUnixEpoch",0).expect("Could not get method");
+        let epoch = met.invoke(None,()).expect("Got an exception").expect("Got null").unbox::<DateTime>();
+        assert_eq!(epoch.ticks(),621_355_968_000_000_000);
+        assert_eq!(epoch.to_system_time(),SystemTime::UNIX_EPOCH);
+    }
+    #[test]
+    fn datetime_to_managed(){
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","DateTimeProvider").expect("Could not get class");
+        let met:Method<(DateTime,)> = Method::get_from_name(&class,"GetTicks",1).expect("Could not get method");
+        let time = SystemTime::UNIX_EPOCH + Duration::from_secs(86_400);
+        let date = DateTime::from_system_time(time);
+        let ticks = met.invoke(None,(date,)).expect("Got an exception").expect("Got null").unbox::<i64>();
+        assert_eq!(ticks,date.ticks());
+        assert_eq!(date.to_system_time(),time);
+        let before_epoch = SystemTime::UNIX_EPOCH - Duration::from_millis(1500);
+        assert_eq!(DateTime::from_system_time(before_epoch).to_system_time(),before_epoch);
+    }
+}
@@ -1,4 +1,6 @@
-/*
+use crate as wrapped_mono;
+use rusty_fork::rusty_fork_test;
+use wrapped_mono::*;
 rusty_fork_test! {
     #[test]
     fn getting_delegate_from_method(){
@@ -9,6 +11,12 @@ rusty_fork_test! {
         let met:Method<()> = Method::get_from_name(&class,"GetDelegate",0).unwrap();
         let obj = met.invoke(None,()).expect("Got an Exception").expect("Got null on a non-nullable!");
         assert!(obj.get_class().is_delegate());
+        let del:Delegate = obj.cast().expect("Expected delegate, got something else");
+        assert_eq!(del.get_params(),vec![Class::get_int_32(),Class::get_int_32()]);
+        // DelFNC is static, so there is no target.
+        assert!(del.get_target().is_none());
+        let method:Method<(i32,i32)> = del.get_method().expect("Could not get method of a delegate");
+        assert_eq!(method.get_name(),"DelFNC");
     }
     #[test]
     fn calling_delegate_from_method(){
@@ -18,7 +26,38 @@ rusty_fork_test! {
         let class = Class::from_name(&img,"","TestFunctions").expect("Could not get class");
         let met:Method<()> = Method::get_from_name(&class,"GetDelegate",0).unwrap();
         let obj = met.invoke(None,()).expect("Got an Exception").expect("Got null on a non-nullable!");
-        let del:Delegate<(i32,i32)> = Delegate::cast_from_object(&obj).expect("Expected delegate, got something else");
-        let _res = del.invoke((10,10)).expect("Exception").expect("Got null");
+        let del:Delegate = obj.cast().expect("Expected delegate, got something else");
+        let res = del.invoke(&[&13,&10]).expect("Exception").expect("Got null");
+        assert_eq!(res.unbox::<i32>(),16);
+        // Wrong argument count or types are rejected instead of being passed to the runtime.
+        assert!(del.invoke(&[&13]).is_err());
+        assert!(del.invoke(&[&13.0_f64,&10]).is_err());
     }
-}*/
+    #[test]
+    fn calling_delegate_with_string_during_collection(){
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not get class");
+        let met:Method<()> = Method::get_from_name(&class,"GetCollectingLengthDelegate",0).unwrap();
+        let del:Delegate = met.invoke(None,()).expect("Got an Exception").expect("Got null").cast().expect("Expected delegate");
+        // Managed string created for the argument must survive a collection run by the delegate.
+        for _ in 0..8{
+            let res = del.invoke(&[&"some text".to_owned()]).expect("Exception").expect("Got null");
+            assert_eq!(res.unbox::<i32>(),9);
+        }
+    }
+    #[test]
+    fn invoking_delegate_from_internal_call(){
+        #[invokable]
+        fn call_func(func:Delegate) -> i32{
+            func.invoke(&[&21]).expect("Got an exception").expect("Got null").unbox::<i32>()
+        }
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Pinvoke.dll").unwrap();
+        add_internal_call!("Test::CallFunc",call_func);
+        let class = Class::from_name(&asm.get_image(),"","Test").expect("Could not get class");
+        let met:Method<()> = Method::get_from_name(&class,"CallDoubling",0).expect("Could not get method");
+        let res = met.invoke(None,()).expect("Got an exception").expect("Got null").unbox::<i32>();
+        assert_eq!(res,42);
+    }
+}
@@ -13,4 +13,37 @@ rusty_fork_test! {
         let _domain = jit::init("main",None);
         let _execepion = Exception::argument_exception("arg1","exception!");
     }
+    #[test]
+    fn catch_divide_by_zero(){
+        use wrapped_mono::*;
+        let domain = jit::init("main",None);
+        let asm = domain.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not get class");
+        let met:Method<(i32,i32)> = Method::get_from_name(&class,"Divide",2).expect("Could not get method");
+        let exception = met.invoke(None,(1,0)).err().expect("Dividing by zero did not throw!");
+        assert!(exception.class_name() == "System.DivideByZeroException","{}",exception.class_name());
+        let message = exception.message();
+        assert!(message.to_lowercase().contains("divide by zero"),"{}",message);
+        let err:Box<dyn std::error::Error> = Box::new(exception);
+        assert!(err.to_string().contains(&message));
+    }
+    #[test]
+    fn exception_stack_trace_and_inner(){
+        use wrapped_mono::*;
+        let domain = jit::init("main",None);
+        let asm = domain.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not get class");
+        let met:Method<()> = Method::get_from_name(&class,"NestedExceptionThrower",0).expect("Could not get method");
+        let exception = met.invoke(None,()).err().expect("Method did not throw!");
+        assert_eq!(exception.class_name(),"System.ApplicationException");
+        let trace = exception.stack_trace().expect("Thrown exception has no stack trace");
+        assert!(trace.contains("NestedExceptionThrower"),"{}",trace);
+        let inner = exception.inner().expect("Inner exception is missing");
+        assert_eq!(inner.class_name(),"System.InvalidOperationException");
+        assert_eq!(inner.message(),"Logfile cannot be read-only");
+        assert!(inner.stack_trace().expect("Inner exception has no stack trace").contains("ExceptionThrower"));
+        assert!(inner.inner().is_none());
+        // Exceptions which were never thrown have no stack trace.
+        assert!(Exception::not_implemented("not thrown").stack_trace().is_none());
+    }
 }
@@ -26,6 +26,32 @@ rusty_fork_test! {
         }
     }
     #[test]
+    fn test_gc_heap_statistics(){
+        let dom = jit::init("dom",None);
+        let objects:Vec<Object> = (0..10_000).map(|i| Object::box_val::<i32>(&dom,i)).collect();
+        let used = gc::get_used_size();
+        let heap = gc::get_heap_size();
+        assert!(used > 0,"{} <= 0",used);
+        assert!(heap >= used,"{} < {}",heap,used);
+        let collections = gc::collection_count(gc::max_generation());
+        gc::collect();
+        assert!(gc::collection_count(gc::max_generation()) > collections);
+        assert!(gc::get_used_size() >= 0);
+        assert!(gc::get_heap_size() >= 0);
+        assert!(gc::max_generation() >= 0);
+        drop(objects);
+    }
+    #[test]
+    fn test_gc_name(){
+        let _dom = jit::init("dom",None);
+        let name = gc::get_gc_name();
+        assert!(!name.is_empty());
+        assert!(name == "sgen" || name == "boehm","Unknown collector \"{}\"",name);
+        if name == "boehm"{
+            assert!(!gc::is_concurrent());
+        }
+    }
+    #[test]
     fn test_gc_object_multiref(){
 
         let dom = jit::init("dom",None);
@@ -121,4 +147,23 @@ rusty_fork_test! {
             obj.get_size();
         }
     }
+    #[test]
+    fn pinned_handle_addr(){
+        let dom = jit::init("dom",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not get class");
+        let arr:Array<Dim1D,u8> = Array::new(&dom,&[4]);
+        let pinned = gc::GCHandle::new_pinned(&arr.to_object());
+        let data = pinned.addr().cast::<u8>();
+        for i in 0..4{
+            unsafe{data.add(i).write(i as u8 + 1)};
+        }
+        // Address does not change after a collection, since the array is pinned.
+        gc::collect();
+        assert_eq!(pinned.addr().cast::<u8>(),data);
+        let sum:Method<(Array<Dim1D,u8>,)> = Method::get_from_name(&class,"SumByteArray",1).expect("Could not find method!");
+        let res = sum.invoke(None,(arr,)).expect("Got an exception").expect("Got null").unbox::<i32>();
+        assert_eq!(res,1 + 2 + 3 + 4);
+        gc::GCHandle::free(pinned);
+    }
 }
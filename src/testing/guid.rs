@@ -0,0 +1,31 @@
+use crate as wrapped_mono;
+use rusty_fork::rusty_fork_test;
+use wrapped_mono::*;
+#[invokable]
+fn check_guid_bytes(guid: Guid, expected: Array<Dim1D, u8>) -> bool {
+    guid.to_bytes().to_vec() == expected.to_vec()
+}
+rusty_fork_test! {
+    #[test]
+    fn guid_layout_matches_to_byte_array(){
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Pinvoke.dll").unwrap();
+        add_internal_call!("Test::CheckGuidBytes",check_guid_bytes);
+        let class = Class::from_name(&asm.get_image(),"","Test").expect("Could not get class");
+        let met:Method<()> = Method::get_from_name(&class,"PassGuid",0).expect("Could not get method");
+        let res = met.invoke(None,()).expect("Got an exception").expect("Got null").unbox::<bool>();
+        assert!(res);
+    }
+    #[test]
+    fn guid_unbox(){
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Pinvoke.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","Test").expect("Could not get class");
+        let met:Method<()> = Method::get_from_name(&class,"GetBoxedGuid",0).expect("Could not get method");
+        let guid = met.invoke(None,()).expect("Got an exception").expect("Got null").unbox::<Guid>();
+        // First three groups are stored as little-endian integers.
+        let expected = Guid::from_bytes([0x33,0x22,0x11,0x00,0x55,0x44,0x77,0x66,0x88,0x99,0xaa,0xbb,0xcc,0xdd,0xee,0xff]);
+        assert_eq!(guid,expected);
+        assert_eq!(guid.to_bytes(),expected.to_bytes());
+    }
+}
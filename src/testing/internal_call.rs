@@ -7,11 +7,54 @@ mod some_mod {
     #[invokable]
     pub fn some_fn() {}
 }
-///invokable macro does not work with <>
 #[invokable]
 fn get_object() -> Option<wrapped_mono::object::Object> {
     None
 }
+#[invokable]
+fn get_some_object() -> Option<wrapped_mono::object::Object> {
+    let domain = wrapped_mono::Domain::get_current().expect("Could not get domain");
+    Some(wrapped_mono::Object::box_val::<i32>(&domain, 5))
+}
+#[invokable]
+fn is_null_object(obj: Option<wrapped_mono::object::Object>) -> bool {
+    obj.is_none()
+}
+/// Custom type marshalled as `int`, used to test user implemented interop traits.
+#[repr(transparent)]
+struct Meters(i32);
+impl wrapped_mono::InteropReceive for Meters {
+    type SourceType = i32;
+    fn get_rust_rep(mono_arg: Self::SourceType) -> Self {
+        Self(mono_arg)
+    }
+}
+unsafe impl wrapped_mono::InteropSend for Meters {}
+impl wrapped_mono::InteropClass for Meters {
+    fn get_mono_class() -> wrapped_mono::Class {
+        wrapped_mono::Class::get_int_32()
+    }
+}
+#[invokable]
+fn double_meters(meters: Meters) -> Meters {
+    Meters(meters.0 * 2)
+}
+/// Struct passed to managed code by a byte-wise copy.
+#[derive(MonoBlittable, Clone, Copy)]
+#[repr(C)]
+struct Vec3 {
+    x: f32,
+    y: f32,
+    z: f32,
+}
+#[invokable]
+fn double_vec3(vec: Vec3) -> Vec3 {
+    Vec3 {
+        x: vec.x * 2.0,
+        y: vec.y * 2.0,
+        z: vec.z * 2.0,
+    }
+}
 
 rusty_fork_test! {
     #[test]
@@ -37,9 +80,12 @@ rusty_fork_test! {
         fn pass_data_array(input:Array<Dim1D,i32>){
             let len = input.len();
             let size = input.get_size();
-            println!("size:{}",size);
-            assert!(size == 56);
-            assert!(len == 6);
+            assert_eq!(len,6);
+            assert_eq!(input.length(0),6);
+            assert_eq!(Array::<Dim1D,i32>::element_size(),std::mem::size_of::<i32>());
+            assert_eq!(size,24);
+            // Managed object size also includes the object header and array bounds.
+            assert!(ObjectTrait::get_size(&input) as usize > size);
             for i in 0..len{
                 println!("i:{}",i);
                 assert!(input.get([i]) == i as i32);
@@ -64,4 +110,231 @@ rusty_fork_test! {
 
         let _res = jit::exec(&dom,&asm,args);
     }
+    #[test]
+    fn internal_call_raise_exception(){
+        use crate as wrapped_mono;
+        use wrapped_mono::*;
+        #[invokable]
+        fn throw_argument_null(){
+            let exception = Exception::argument_null("x");
+            unsafe{exception.raise()};
+        }
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Pinvoke.dll").unwrap();
+        add_internal_call!("Test::ThrowArgumentNull",throw_argument_null);
+        let class = Class::from_name(&asm.get_image(),"","Test").expect("Could not get class");
+        let met:Method<()> = Method::get_from_name(&class,"CatchArgumentNull",0).expect("Could not get method");
+        let caught = met.invoke(None,()).expect("Exception was not caught!").expect("Got null").unbox::<bool>();
+        assert!(caught);
+    }
+    #[test]
+    fn internal_call_return_result(){
+        use crate as wrapped_mono;
+        use wrapped_mono::*;
+        #[invokable]
+        fn checked_divide(x:i32) -> Result<i32,Exception>{
+            if x == 0{
+                return Err(Exception::argument_null("x"));
+            }
+            Ok(10 / x)
+        }
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Pinvoke.dll").unwrap();
+        add_internal_call!("Test::CheckedDivide",checked_divide);
+        let class = Class::from_name(&asm.get_image(),"","Test").expect("Could not get class");
+        let met:Method<()> = Method::get_from_name(&class,"CatchCheckedDivide",0).expect("Could not get method");
+        let caught = met.invoke(None,()).expect("Exception was not caught!").expect("Got null").unbox::<bool>();
+        assert!(caught);
+    }
+    #[test]
+    fn internal_call_custom_interop_type(){
+        use crate as wrapped_mono;
+        use wrapped_mono::*;
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Pinvoke.dll").unwrap();
+        add_internal_call!("Test::DoubleMeters",double_meters);
+        let class = Class::from_name(&asm.get_image(),"","Test").expect("Could not get class");
+        let met:Method<(i32,)> = Method::get_from_name(&class,"CallDoubleMeters",1).expect("Could not get method");
+        let res = met.invoke(None,(21,)).expect("Got an exception").expect("Got null").unbox::<i32>();
+        assert_eq!(res,42);
+    }
+    #[test]
+    fn internal_call_tuples(){
+        use crate as wrapped_mono;
+        use wrapped_mono::*;
+        #[invokable]
+        fn get_pair() -> (i32,f64){
+            (5,2.5)
+        }
+        #[invokable]
+        fn sum_pair(pair:(i32,f64)) -> f64{
+            f64::from(pair.0) + pair.1
+        }
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Pinvoke.dll").unwrap();
+        add_internal_call!("Test::GetPair",get_pair);
+        add_internal_call!("Test::SumPair",sum_pair);
+        let class = Class::from_name(&asm.get_image(),"","Test").expect("Could not get class");
+        let met:Method<()> = Method::get_from_name(&class,"CheckPair",0).expect("Could not get method");
+        let res = met.invoke(None,()).expect("Got an exception").expect("Got null").unbox::<bool>();
+        assert!(res);
+    }
+    #[test]
+    fn internal_call_isinst(){
+        use crate as wrapped_mono;
+        use wrapped_mono::*;
+        #[invokable]
+        fn is_string(obj:Object) -> bool{
+            obj.isinst(&Class::get_string()).is_some() && obj.isinst(&Class::get_int_32()).is_none()
+        }
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Pinvoke.dll").unwrap();
+        add_internal_call!("Test::IsString",is_string);
+        let class = Class::from_name(&asm.get_image(),"","Test").expect("Could not get class");
+        let met:Method<()> = Method::get_from_name(&class,"CallIsString",0).expect("Could not get method");
+        let res = met.invoke(None,()).expect("Got an exception").expect("Got null").unbox::<bool>();
+        assert!(res);
+    }
+    #[test]
+    fn internal_call_blittable_struct(){
+        use crate as wrapped_mono;
+        use wrapped_mono::*;
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Pinvoke.dll").unwrap();
+        add_internal_call!("Test::DoubleVec3",double_vec3);
+        let class = Class::from_name(&asm.get_image(),"","Test").expect("Could not get class");
+        let met:Method<()> = Method::get_from_name(&class,"CheckVec3",0).expect("Could not get method");
+        let res = met.invoke(None,()).expect("Got an exception").expect("Got null").unbox::<bool>();
+        assert!(res);
+    }
+    #[test]
+    fn unbox_blittable_struct(){
+        use crate as wrapped_mono;
+        use wrapped_mono::*;
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Pinvoke.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","Test").expect("Could not get class");
+        let met:Method<()> = Method::get_from_name(&class,"GetBoxedVec3",0).expect("Could not get method");
+        let boxed = met.invoke(None,()).expect("Got an exception").expect("Got null");
+        let vec:Vec3 = boxed.unbox_struct().expect("Could not unbox Vec3");
+        assert_eq!((vec.x,vec.y,vec.z),(1.0,2.0,3.0));
+        // Sizes do not match.
+        assert!(Object::box_val::<i32>(&dom,5).unbox_struct::<Vec3>().is_err());
+        // Not a value type.
+        assert!(Object::new(&dom,&class).unbox_struct::<Vec3>().is_err());
+    }
+    #[test]
+    fn internal_call_returning_array(){
+        use crate as wrapped_mono;
+        use wrapped_mono::*;
+        #[invokable]
+        fn make_squares(len:i32) -> Array<Dim1D,i32>{
+            let dom = Domain::get_current().expect("No current domain");
+            let data:Vec<i32> = (0..len).map(|i| i * i).collect();
+            let mut arr = Array::new(&dom,&[data.len()]);
+            arr.copy_from_slice(&data);
+            arr
+        }
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Pinvoke.dll").unwrap();
+        add_internal_call!("Test::MakeSquares",make_squares);
+        let class = Class::from_name(&asm.get_image(),"","Test").expect("Could not get class");
+        let met:Method<()> = Method::get_from_name(&class,"SumMadeSquares",0).expect("Could not get method");
+        let res = met.invoke(None,()).expect("Got an exception").expect("Got null").unbox::<i32>();
+        // 4 elements: 0 + 1 + 4 + 9
+        assert_eq!(res,4 * 100 + 14);
+    }
+    #[test]
+    fn internal_call_nullable_value(){
+        use crate as wrapped_mono;
+        use wrapped_mono::*;
+        #[invokable]
+        fn describe_nullable(x:Option<i32>) -> i32{
+            x.unwrap_or(-1)
+        }
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Pinvoke.dll").unwrap();
+        add_internal_call!("Test::DescribeNullable",describe_nullable);
+        let class = Class::from_name(&asm.get_image(),"","Test").expect("Could not get class");
+        let met:Method<()> = Method::get_from_name(&class,"CallDescribeNullable",0).expect("Could not get method");
+        let res = met.invoke(None,()).expect("Got an exception").expect("Got null").unbox::<i32>();
+        assert_eq!(res,5 * 10 - 1);
+        let met:Method<(Option<i32>,)> = Method::get_from_name(&class,"NullableOrDefault",1).expect("Could not get method");
+        let res = met.invoke(None,(Some(3),)).expect("Got an exception").expect("Got null").unbox::<i32>();
+        assert_eq!(res,3);
+        let res = met.invoke(None,(None,)).expect("Got an exception").expect("Got null").unbox::<i32>();
+        assert_eq!(res,-1);
+    }
+    #[test]
+    fn internal_call_returning_nullable_value(){
+        use crate as wrapped_mono;
+        use wrapped_mono::*;
+        #[invokable]
+        fn maybe_double(x:i32) -> Option<i32>{
+            (x >= 0).then_some(x * 2)
+        }
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Pinvoke.dll").unwrap();
+        add_internal_call!("Test::MaybeDouble",maybe_double);
+        let class = Class::from_name(&asm.get_image(),"","Test").expect("Could not get class");
+        let met:Method<()> = Method::get_from_name(&class,"CallMaybeDouble",0).expect("Could not get method");
+        let res = met.invoke(None,()).expect("Got an exception").expect("Got null").unbox::<i32>();
+        assert_eq!(res,8 * 10);
+    }
+    #[test]
+    fn internal_call_scalars(){
+        use crate as wrapped_mono;
+        use wrapped_mono::*;
+        use std::sync::Mutex;
+        static RECEIVED:Mutex<Vec<(bool,f32,f64)>> = Mutex::new(Vec::new());
+        #[invokable]
+        fn pass_scalars(b:bool,x:f32,y:f64){
+            RECEIVED.lock().unwrap().push((b,x,y));
+        }
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Pinvoke.dll").unwrap();
+        add_internal_call!("Test::PassScalars",pass_scalars);
+        let class = Class::from_name(&asm.get_image(),"","Test").expect("Could not get class");
+        let met:Method<()> = Method::get_from_name(&class,"CallPassScalars",0).expect("Could not get method");
+        met.invoke(None,()).expect("Got an exception");
+        assert_eq!(*RECEIVED.lock().unwrap(),vec![(true,1.5,0.1),(false,-3.25,1e300)]);
+        // Any non-zero MonoBoolean is true.
+        assert!(<bool as InteropReceive>::get_rust_rep(2));
+        assert!(!<bool as InteropReceive>::get_rust_rep(0));
+    }
+    #[test]
+    fn internal_call_optional_objects(){
+        use crate as wrapped_mono;
+        use wrapped_mono::*;
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Pinvoke.dll").unwrap();
+        add_internal_call!("Test::GetObject",get_object);
+        add_internal_call!("Test::GetSomeObject",get_some_object);
+        add_internal_call!("Test::IsNullObject",is_null_object);
+        let class = Class::from_name(&asm.get_image(),"","Test").expect("Could not get class");
+        let met:Method<()> = Method::get_from_name(&class,"CheckOptionalObjects",0).expect("Could not get method");
+        let res = met.invoke(None,()).expect("Got an exception").expect("Got null").unbox::<bool>();
+        assert!(res);
+    }
+    #[test]
+    fn internal_call_string_array(){
+        use crate as wrapped_mono;
+        use wrapped_mono::*;
+        use std::sync::Mutex;
+        static RECEIVED:Mutex<Vec<String>> = Mutex::new(Vec::new());
+        #[invokable]
+        fn pass_string_array(strings:Array<Dim1D,MString>){
+            let mut received = RECEIVED.lock().unwrap();
+            for i in 0..strings.len(){
+                received.push(strings.get([i]).to_string());
+            }
+        }
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Pinvoke.dll").unwrap();
+        add_internal_call!("Test::PassStringArray",pass_string_array);
+        let class = Class::from_name(&asm.get_image(),"","Test").expect("Could not get class");
+        let met:Method<()> = Method::get_from_name(&class,"CallPassStringArray",0).expect("Could not get method");
+        met.invoke(None,()).expect("Got an exception");
+        assert_eq!(*RECEIVED.lock().unwrap(),vec!["a","b","c"]);
+    }
 }
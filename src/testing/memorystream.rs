@@ -0,0 +1,19 @@
+use crate as wrapped_mono;
+use rusty_fork::rusty_fork_test;
+use wrapped_mono::*;
+rusty_fork_test! {
+    #[test]
+    fn memorystream_round_trip(){
+        let dom = jit::init("root",None);
+        let bytes:Vec<u8> = (0..=255).collect();
+        let stream = memorystream::from_bytes(&dom,&bytes);
+        assert!(stream.get_class().get_name() == "MemoryStream");
+        assert_eq!(memorystream::to_bytes(&stream),bytes);
+    }
+    #[test]
+    fn memorystream_empty(){
+        let dom = jit::init("root",None);
+        let stream = memorystream::from_bytes(&dom,&[]);
+        assert!(memorystream::to_bytes(&stream).is_empty());
+    }
+}
@@ -2,7 +2,7 @@ use crate as wrapped_mono;
 
 use rusty_fork::rusty_fork_test;
 
-use wrapped_mono::{class::Class, jit, method::Method};
+use wrapped_mono::{class::Class, jit, method::Method, object::ObjectTrait};
 rusty_fork_test! {
     #[test]
     fn getting_method(){
@@ -328,6 +328,204 @@ rusty_fork_test! {
             Err(_)=>(),
         };
     }
+    #[test]
+    fn can_invoke_with(){
+        use wrapped_mono::MString;
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let img = asm.get_image();
+        let class = Class::from_name(&img,"","TestFunctions").expect("Could not get class");
+        let met:Method<(MString,MString,MString,MString)> = Method::get_from_name(&class,"StrTest",4).unwrap();
+        let string = Class::get_string();
+        assert!(met.can_invoke_with(&[string,string,string,string]));
+        assert!(!met.can_invoke_with(&[string,string,Class::get_object(),string]));
+        assert!(!met.can_invoke_with(&[string,string,string]));
+        let met:Method<(i32,)> = Method::get_from_name(&class,"GetArg",1).unwrap();
+        assert!(met.can_invoke_with(&[Class::get_int_32()]));
+        assert!(!met.can_invoke_with(&[string]));
+    }
+    #[test]
+    fn method_thunk(){
+        use crate::binds::MonoException;
+        let main = jit::init("main",None);
+        let asm = main.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not find class!");
+        let met:Method<(i32,)> = Method::get_from_name(&class,"GetArg",1).expect("Could not find method!");
+        let thunk:extern "C" fn(i32,*mut *mut MonoException)->i32 = unsafe{met.get_thunk()};
+        const CALLS:i32 = 10_000;
+        let mut exception:*mut MonoException = std::ptr::null_mut();
+        let mut thunk_sum:i64 = 0;
+        for i in 0..CALLS{
+            thunk_sum += i64::from(thunk(i,&mut exception));
+            assert!(exception.is_null());
+        }
+        let mut invoke_sum:i64 = 0;
+        for i in 0..CALLS{
+            invoke_sum += i64::from(met.invoke(None,(i,)).expect("Got an exception").expect("Got null").unbox::<i32>());
+        }
+        assert_eq!(thunk_sum,invoke_sum);
+    }
+    #[test]
+    fn method_invoke_raw(){
+        use wrapped_mono::object::ObjectTrait;
+        let main = jit::init("main",None);
+        let asm = main.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not find class!");
+        let get_arg:Method<(i32,)> = Method::get_from_name(&class,"GetArg",1).expect("Could not find method!");
+        let mut arg:i32 = 7;
+        let (res,exception) = unsafe{get_arg.invoke_raw(None,&[std::ptr::addr_of_mut!(arg).cast()])};
+        assert!(exception.is_none());
+        assert_eq!(res.expect("Got null").unbox::<i32>(),7);
+        let thrower:Method<()> = Method::get_from_name(&class,"ExceptionThrower",0).expect("Could not find method!");
+        let (res,exception) = unsafe{thrower.invoke_raw(None,&[])};
+        assert!(res.is_none());
+        let exception = exception.expect("Exception was not thrown!");
+        assert_eq!(exception.get_class().get_name(),"InvalidOperationException");
+    }
+    #[test]
+    fn method_header(){
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let img = asm.get_image();
+        let class = Class::from_name(&img,"","TestFunctions").expect("Could not get class");
+        let sum:Method<(wrapped_mono::Array<wrapped_mono::Dim1D,i32>,)> = Method::get_from_name(&class,"SumIntArray",1).expect("Could not find method!");
+        let header = sum.get_header().expect("Method with a body has no header!");
+        assert!(header.code_size() > 0);
+        assert!(header.max_stack() > 0);
+        assert!(header.num_locals() > 0);
+        let abstract_class = Class::from_name(&img,"","AbstractClass").expect("Could not get class");
+        let abstract_fn:Method<()> = Method::get_from_name(&abstract_class,"AbstractFunction",0).expect("Could not find method!");
+        assert!(abstract_fn.get_header().is_none());
+    }
+    #[test]
+    fn method_reflection_object(){
+        use wrapped_mono::MString;
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let img = asm.get_image();
+        let class = Class::from_name(&img,"","TestFunctions").expect("Could not get class");
+        let met:Method<()> = Method::get_from_name(&class,"GetOne",0).expect("Could not find method!");
+        let method_info = met.get_reflection_object(&dom);
+        let name:MString = method_info.call("get_Name",()).expect("Got an exception").expect("Got null").cast().expect("Name is not a string");
+        assert_eq!(name.to_string(),met.get_name());
+    }
+    #[test]
+    fn method_out_and_ref_params(){
+        use wrapped_mono::InvokeArgRef;
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not get class");
+        let try_parse:Method<(String,InvokeArgRef<i32>)> = Method::get_from_name(&class,"TryParseInt",2).expect("Could not find method!");
+        let mut value = 0;
+        let res = try_parse.invoke(None,("42".to_owned(),InvokeArgRef::new(&mut value))).expect("Got an exception").expect("Got null");
+        assert!(res.unbox::<bool>());
+        assert_eq!(value,42);
+        let res = try_parse.invoke(None,("not a number".to_owned(),InvokeArgRef::new(&mut value))).expect("Got an exception").expect("Got null");
+        assert!(!res.unbox::<bool>());
+        assert_eq!(value,0);
+        let append:Method<(InvokeArgRef<String>,)> = Method::get_from_name(&class,"AppendSuffix",1).expect("Could not find method!");
+        let mut text = "hello".to_owned();
+        append.invoke(None,(InvokeArgRef::new(&mut text),)).expect("Got an exception");
+        assert_eq!(text,"hello!");
+        // Arguments passed by reference can't be passed to parameters passed by value.
+        assert!(Method::<(InvokeArgRef<i32>,)>::get_from_name(&class,"GetArg",1).is_none());
+    }
+    #[test]
+    fn method_from_token(){
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let img = asm.get_image();
+        let class = Class::from_name(&img,"","TestFunctions").expect("Could not get class");
+        let met:Method<(i32,)> = Method::get_from_name(&class,"GetArg",1).expect("Could not get method");
+        let resolved:Method<(i32,)> = img.get_method(met.get_token()).expect("Could not resolve method from its token");
+        assert!(resolved.get_ptr() == met.get_ptr());
+        // Row 0 does not exist in any table.
+        assert!(img.get_method::<()>(0x0600_0000).is_none());
+        assert!(img.get_method::<()>(0x06FF_FFFF).is_none());
+    }
+    #[test]
+    fn method_invoke_args(){
+        use wrapped_mono::{MethodArgs, MString};
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not get class");
+        let met:Method<(i32,String,f64)> = Method::get_from_name(&class,"JoinArgs",3).expect("Could not get method");
+        let args = MethodArgs::new().add(42_i32).add("hello").add(3.5_f64);
+        assert_eq!(args.len(),3);
+        let res = met.invoke_args(None,&args).expect("Got an exception").expect("Got null");
+        let res:MString = res.cast().expect("Expected a string");
+        assert_eq!(res.to_string(),"42hello3.5");
+        // Arguments of wrong types are rejected.
+        let args = MethodArgs::new().add(42_i32).add(1_i32).add(3.5_f64);
+        assert!(met.invoke_args(None,&args).is_err());
+    }
+    #[test]
+    fn method_invoke_args_string_during_collection(){
+        use wrapped_mono::MethodArgs;
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not get class");
+        let met:Method<(String,)> = Method::get_from_name(&class,"CollectAndGetLength",1).expect("Could not get method");
+        let args = MethodArgs::new().add("some text");
+        // Managed string created for the argument must survive a collection run by the method.
+        for _ in 0..8{
+            let res = met.invoke_args(None,&args).expect("Got an exception").expect("Got null").unbox::<i32>();
+            assert_eq!(res,9);
+        }
+    }
+    #[test]
+    fn method_inflate(){
+        use wrapped_mono::Object;
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not get class");
+        let identity:Method<(Object,)> = Method::get_from_name(&class,"Identity",1).expect("Could not get method");
+        let int_identity:Method<(i32,)> = identity.inflate(&[Class::get_int_32()]).expect("Could not inflate method");
+        let res = int_identity.invoke(None,(7,)).expect("Got an exception").expect("Got null").unbox::<i32>();
+        assert_eq!(res,7);
+        // Wrong number of type arguments, or a method which is not generic, can't be inflated.
+        assert!(identity.inflate::<(i32,)>(&[Class::get_int_32(),Class::get_int_32()]).is_none());
+        let get_one:Method<()> = Method::get_from_name(&class,"GetOne",0).expect("Could not get method");
+        assert!(get_one.inflate::<()>(&[Class::get_int_32()]).is_none());
+    }
+    #[test]
+    fn method_invoke_with_object_array(){
+        use wrapped_mono::Object;
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not get class");
+        let met:Method<(i32,i32)> = Method::get_from_name(&class,"Add",2).expect("Could not get method");
+        let args = [Object::box_val(&dom,40_i32),Object::box_val(&dom,2_i32)];
+        let res = met.invoke_with_object_array(None,&args).expect("Got an exception").expect("Got null").unbox::<i32>();
+        assert_eq!(res,42);
+        // Wrong number of arguments is reported as an exception before the method is invoked.
+        let err = met.invoke_with_object_array(None,&args[..1]).err().expect("Expected an exception");
+        assert_eq!(err.class_name(),"System.Reflection.TargetParameterCountException");
+        let too_many = [args[0].clone(),args[1].clone(),args[1].clone()];
+        assert!(met.invoke_with_object_array(None,&too_many).is_err());
+        // So are arguments of wrong types.
+        let wrong_type = [args[0].clone(),Object::box_val(&dom,2.0_f64)];
+        let err = met.invoke_with_object_array(None,&wrong_type).err().expect("Expected an exception");
+        assert_eq!(err.class_name(),"System.ArgumentException");
+    }
+    #[test]
+    fn method_custom_attributes(){
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let img = asm.get_image();
+        let class = Class::from_name(&img,"","PluginClass").expect("Could not get class");
+        let benchmark = Class::from_name(&img,"","BenchmarkAttribute").expect("Could not get class");
+        let plugin = Class::from_name(&img,"","PluginAttribute").expect("Could not get class");
+        let met:Method<()> = Method::get_from_name(&class,"BenchmarkedFunction",0).expect("Could not find method!");
+        let attrs = met.get_custom_attributes(Some(&benchmark));
+        assert_eq!(attrs.len(),1);
+        assert_eq!(attrs[0].get_class(),benchmark);
+        let iterations = attrs[0].call("get_Iterations",()).expect("Got an exception").expect("Got null").unbox::<i32>();
+        assert_eq!(iterations,100);
+        assert!(met.get_custom_attributes(Some(&plugin)).is_empty());
+        let other:Method<()> = Method::get_from_name(&class,"NotBenchmarkedFunction",0).expect("Could not find method!");
+        assert!(other.get_custom_attributes(None).is_empty());
+    }
 }
 use crate::InteropBox;
 use crate::{InteropClass, InteropReceive, InteropSend};
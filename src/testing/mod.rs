@@ -3,15 +3,19 @@ use rusty_fork::rusty_fork_test;
 mod array;
 mod benchmarks;
 mod class;
+mod datetime;
 mod delegate;
 mod exception;
 mod gc;
+mod guid;
 mod internal_call;
+mod memorystream;
 mod method;
 mod object;
 #[cfg(feature = "profiler_api")]
 mod profiler;
 mod reflection;
+mod thread;
 use crate as wrapped_mono;
 use mstring::MString;
 use wrapped_mono::*;
@@ -92,6 +96,88 @@ rusty_fork_test! {
         dom.assembly_open("test/dlls/Missing.dll").unwrap();
     }
     #[test]
+    fn checked_assembly_loading(){
+        use wrapped_mono::jit;
+        let _dom = jit::init("root",None);
+        let asm = Assembly::open("test/dlls/Test.dll").expect("Could not load assembly");
+        assert!(asm.get_name() == "Test");
+    }
+    #[test]
+    fn checked_missing_assembly_loading(){
+        use wrapped_mono::jit;
+        let _dom = jit::init("root",None);
+        let err = Assembly::open("test/dlls/Missing.dll").err();
+        assert!(err == Some(AssemblyLoadError::ErrorErrno),"{:?}",err);
+    }
+    #[test]
+    fn checked_invalid_assembly_loading(){
+        use wrapped_mono::jit;
+        let _dom = jit::init("root",None);
+        let err = Assembly::open("test/Test.cs").err();
+        assert!(err == Some(AssemblyLoadError::ImageInvalid),"{:?}",err);
+    }
+    #[test]
+    fn checked_missing_reference_assembly_loading(){
+        use wrapped_mono::jit;
+        let _dom = jit::init("root",None);
+        // Copy the assembly alone, so the assembly it references(Test.dll) can't be found next to it.
+        let dir = std::env::temp_dir().join("wrapped_mono_missing_reference");
+        std::fs::create_dir_all(&dir).expect("Could not create directory");
+        let path = dir.join("Dependent.dll");
+        std::fs::copy("test/dlls/Dependent.dll",&path).expect("Could not copy assembly");
+        let err = Assembly::open(path.to_str().unwrap()).err();
+        assert!(err == Some(AssemblyLoadError::MissingAssemblyRef),"{:?}",err);
+        // With the reference next to it, the assembly loads.
+        let asm = Assembly::open("test/dlls/Dependent.dll").expect("Could not load assembly");
+        assert!(asm.get_name() == "Dependent");
+    }
+    #[test]
+    fn domain_unload(){
+        use wrapped_mono::jit;
+        let root = jit::init("root",None);
+        let err = root.unload().expect_err("Root domain should not be unloadable");
+        assert_eq!(err.class_name(),"System.CannotUnloadAppDomainException");
+        let secondary = Domain::create();
+        secondary.set(false);
+        let asm = secondary.try_assembly_open("test/dlls/Test.dll").expect("Could not load assembly");
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not get class");
+        let met:Method<()> = Method::get_from_name(&class,"GetOne",0).expect("Could not get method");
+        let res = met.invoke(None,()).expect("Got an exception").expect("Got null").unbox::<i32>();
+        assert_eq!(res,1);
+        secondary.unload().expect("Could not unload domain");
+        assert!(Domain::get_current().expect("No current domain") == root);
+        // Runtime still works after unloading.
+        let asm = root.assembly_open("test/dlls/Test.dll").expect("Could not load assembly");
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not get class");
+        let met:Method<()> = Method::get_from_name(&class,"GetTwo",0).expect("Could not get method");
+        let res = met.invoke(None,()).expect("Got an exception").expect("Got null").unbox::<i32>();
+        assert_eq!(res,2);
+    }
+    #[test]
+    fn assembly_references(){
+        use wrapped_mono::jit;
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Pinvoke.dll").expect("Could not load assembly");
+        let refs = asm.get_references();
+        let corlib = refs.iter().find(|name| name.get_name() == "mscorlib" || name.get_name() == "System.Private.CoreLib").expect("Core library is not referenced!");
+        assert!(corlib.get_major_version() > 0,"{}",corlib);
+        assert_eq!(corlib.get_culture(),"");
+    }
+    #[test]
+    fn create_instance_from_type_name(){
+        use wrapped_mono::jit;
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let obj = dom.create_instance(&asm,"CtorTestClass",&(5,MString::new(&dom,"five"))).expect("Could not create instance");
+        assert!(obj.get_class().get_name() == "CtorTestClass");
+        let corlib = Assembly::assembly_loaded("mscorlib").expect("mscorlib not loaded");
+        let builder = dom.create_instance(&corlib,"System.Text.StringBuilder",&(MString::new(&dom,"abc"),)).expect("Could not create StringBuilder");
+        let content = builder.to_mstring().expect("Exception").expect("Got null").to_string();
+        assert!(content == "abc","{}",content);
+        assert!(matches!(dom.create_instance(&asm,"MissingClass",&()),Err(CreateError::TypeNotFound(_))));
+        assert!(matches!(dom.create_instance(&asm,"CtorTestClass",&(MString::new(&dom,"five"),)),Err(CreateError::ConstructorNotFound(_))));
+    }
+    #[test]
     fn stop_jit(){
         use wrapped_mono::jit;
         let dom = jit::init("root",None);
@@ -132,6 +218,173 @@ rusty_fork_test! {
         assert!(str_txt == &ms.to_string());
     }
     #[test]
+    fn mstring_round_trip(){
+        use wrapped_mono::jit;
+        let dom = jit::init("root",None);
+        let str_txt = "Zażółć gęślą jaźń, ó";
+        let ms = MString::new(&dom,str_txt);
+        assert!(ms.len() == str_txt.chars().count(),"{} != {}",ms.len(),str_txt.chars().count());
+        assert!(!ms.is_empty());
+        assert!(String::from(&ms) == str_txt);
+        assert!(MString::new(&dom,"").is_empty());
+    }
+    #[test]
+    fn mstring_hash_map_key(){
+        use wrapped_mono::jit;
+        use std::collections::HashMap;
+        let dom = jit::init("root",None);
+        let key = MString::new(&dom,"key");
+        let other_key = MString::new(&dom,"key");
+        assert!(key.get_ptr() != other_key.get_ptr());
+        assert!(key == other_key);
+        assert!(key != MString::new(&dom,"other"));
+        let mut map = HashMap::new();
+        map.insert(key,5);
+        assert_eq!(map.get(&other_key),Some(&5));
+    }
+    #[test]
+    fn mstring_intern(){
+        use wrapped_mono::jit;
+        let dom = jit::init("root",None);
+        let first = MString::new(&dom,"interned string");
+        let second = MString::new(&dom,"interned string");
+        assert!(first.get_ptr() != second.get_ptr());
+        let first = first.intern();
+        let second = second.intern();
+        assert!(first.get_ptr() == second.get_ptr());
+        assert_eq!(first.to_string(),"interned string");
+    }
+    #[test]
+    fn jit_init_strict(){
+        use wrapped_mono::jit;
+        let dom = jit::init_strict("root","v4.0.30319").expect("Runtime does not use the default version!");
+        assert!(dom.assembly_open("test/dlls/Test.dll").is_some());
+    }
+    #[test]
+    fn jit_init_strict_bad_version(){
+        use wrapped_mono::jit;
+        let err = jit::init_strict("root","v99.1.2").err().expect("Runtime claims to support a nonsense version!");
+        assert_eq!(err.requested,"v99.1.2");
+        assert!(err.actual.starts_with("4."),"{}",err);
+    }
+    #[test]
+    fn jit_runtime_version(){
+        use wrapped_mono::jit;
+        let _dom = jit::init("root",None);
+        assert!(!jit::runtime_version().is_empty());
+        let corlib = jit::corlib_version();
+        assert!(corlib.starts_with("4."),"{}",corlib);
+    }
+    #[test]
+    fn jit_assembly_preload_hook(){
+        use wrapped_mono::jit;
+        use std::sync::{Arc,Mutex};
+        // Runtime looks for assemblies next to the assembly requesting them, so the requesting assembly and the dependency are copied to separate directories
+        // outside of the default search path, and only the hook can find the dependency.
+        let root = std::env::temp_dir().join("wrapped_mono_preload_hook");
+        let app_dir = root.join("app");
+        let storage = root.join("storage");
+        std::fs::create_dir_all(&app_dir).expect("Could not create application directory");
+        std::fs::create_dir_all(&storage).expect("Could not create assembly storage");
+        let app = app_dir.join("Test.dll");
+        std::fs::copy("test/dlls/Test.dll",&app).expect("Could not copy assembly");
+        let stored = storage.join("Jit.dll");
+        std::fs::copy("test/dlls/Jit.dll",&stored).expect("Could not copy assembly");
+        let requested:Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+        let hook_requested = requested.clone();
+        let dom = jit::init("root",None);
+        jit::install_assembly_preload_hook(move |name|{
+            hook_requested.lock().unwrap().push(name.get_name());
+            if name.get_name() == "Jit"{
+                Assembly::open(stored.to_str().unwrap()).ok()
+            }
+            else{
+                None
+            }
+        });
+        let asm = dom.assembly_open(app.to_str().unwrap()).unwrap();
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not get class");
+        let met:Method<(MString,)> = Method::get_from_name(&class,"LoadAssemblyByName",1).expect("Could not get method");
+        let res = met.invoke(None,(MString::new(&dom,"Jit"),)).expect("Assembly was not resolved by the hook").expect("Got null");
+        let name:MString = res.cast().expect("Expected a string");
+        assert_eq!(name.to_string(),"Jit");
+        assert!(requested.lock().unwrap().iter().any(|name| name == "Jit"));
+        // Assemblies the hook does not know about are still reported as missing.
+        assert!(met.invoke(None,(MString::new(&dom,"MissingAssembly"),)).is_err());
+    }
+    #[test]
+    fn jit_log_handler(){
+        use wrapped_mono::jit;
+        use std::sync::{Arc,Mutex};
+        let messages:Arc<Mutex<Vec<(jit::LogLevel,String)>>> = Arc::new(Mutex::new(Vec::new()));
+        let handler_messages = messages.clone();
+        jit::set_log_handler(move |level,message|{
+            handler_messages.lock().unwrap().push((level,message.to_owned()));
+        });
+        jit::set_log_level(jit::LogLevel::Debug);
+        let dom = jit::init("root",None);
+        let _asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let messages = messages.lock().unwrap();
+        assert!(!messages.is_empty());
+        assert!(messages.iter().any(|(_,message)| !message.is_empty()));
+    }
+    #[test]
+    fn jit_print_handler(){
+        use wrapped_mono::jit;
+        use std::sync::{Arc,Mutex};
+        let out:Arc<Mutex<String>> = Arc::new(Mutex::new(String::new()));
+        let err:Arc<Mutex<String>> = Arc::new(Mutex::new(String::new()));
+        let (handler_out,handler_err) = (out.clone(),err.clone());
+        jit::set_print_handler(move |text| handler_out.lock().unwrap().push_str(text),move |text| handler_err.lock().unwrap().push_str(text));
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not get class");
+        let thrower:Method<()> = Method::get_from_name(&class,"ExceptionThrower",0).expect("Could not find method!");
+        let (_,exception) = unsafe{thrower.invoke_raw(None,&[])};
+        let exception = exception.expect("Exception was not thrown!");
+        // Runtime reports unhandled exceptions through its own error stream.
+        unsafe{binds::mono_print_unhandled_exception(exception.get_ptr())};
+        assert!(err.lock().unwrap().contains("Logfile cannot be read-only"));
+    }
+    #[test]
+    fn jit_unhandled_exception_hook(){
+        use wrapped_mono::jit;
+        let dom = jit::init("root",None);
+        jit::set_unhandled_exception_hook(|exception|{
+            assert_eq!(exception.class_name(),"System.ArgumentException");
+            assert_eq!(exception.message(),"Thrown on a background thread");
+            // Runtime aborts after the hook returns, so a successful test must end the process here.
+            std::process::exit(0);
+        });
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not get class");
+        let thrower:Method<()> = Method::get_from_name(&class,"ThrowOnThread",0).expect("Could not find method!");
+        let _ = thrower.invoke(None,());
+        panic!("Unhandled exception hook was not called!");
+    }
+    #[test]
+    fn image_entry_point(){
+        use wrapped_mono::jit;
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Pinvoke.dll").unwrap();
+        let main:Method<(Array<Dim1D,String>,)> = asm.get_image().get_entry_point().expect("Could not get entry point!");
+        assert_eq!(main.get_name(),"Main");
+        let lib = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let lib_main:Option<Method<(Array<Dim1D,String>,)>> = lib.get_image().get_entry_point();
+        // Test.dll is compiled as a library, so it has no entry point.
+        assert!(lib_main.is_none());
+    }
+    #[test]
+    fn assembly_entry_point(){
+        use wrapped_mono::jit;
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Pinvoke.dll").unwrap();
+        let main:Method<(Array<Dim1D,String>,)> = asm.get_entry_point().expect("Could not get entry point!");
+        assert_eq!(main.get_name(),"Main");
+        let lib = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        assert!(lib.get_entry_point::<(Array<Dim1D,String>,)>().is_none());
+    }
+    #[test]
     fn getting_image_from_assembly(){
         use wrapped_mono::jit;
         let dom = jit::init("root",None);
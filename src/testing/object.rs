@@ -64,4 +64,185 @@ rusty_fork_test! {
         //Gets 0 because constructor not called!
         assert!(unboxed == 0);
     }
+    #[test]
+    fn invoke_delegate_field(){
+        use wrapped_mono::{class::Class,method::Method};
+        let main = jit::init("main",None);
+        let asm = main.assembly_open("test/dlls/Test.dll").unwrap();
+        let img = asm.get_image();
+        let class = Class::from_name(&img,"","DelegateHolder").expect("Could not find class!");
+        let obj = Object::new(&main,&class);
+        let ctor:Method<()> = Method::get_from_name(&class,".ctor",0).expect("Could not find constructor!");
+        ctor.invoke(Some(obj.clone()),()).expect("Exception in constructor!");
+        let res = obj.invoke_delegate_field("callback",&(42,)).expect("Exception in callback!");
+        assert!(res.is_none());
+        let field = class.get_field_from_name("lastValue").expect("Could not find field!");
+        let value = field.get_value::<i32>(&obj).expect("Could not read field!");
+        assert!(value == 42,"{} != 42",value);
+        assert!(obj.invoke_delegate_field("missing",&(42,)).is_err());
+    }
+    #[test]
+    fn object_field_set_and_get_value(){
+        use wrapped_mono::class::Class;
+        let main = jit::init("main",None);
+        let asm = main.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not find class!");
+        let obj = Object::new(&main,&class);
+        let field = class.get_field_from_name("someField").expect("Could not find field!");
+        field.set_value::<i32>(&obj,1234).expect("Could not set field!");
+        let value = field.get_value::<i32>(&obj).expect("Could not read field!");
+        assert!(value == 1234,"{} != 1234",value);
+        assert!(field.get_value::<i64>(&obj).is_err());
+        let counter = class.get_field_from_name("counter").expect("Could not find field!");
+        assert!(counter.get_value::<i32>(&obj).is_err());
+        assert!(counter.set_value::<i32>(&obj,1).is_err());
+        let other = Object::new(&main,&Class::from_name(&asm.get_image(),"","DelegateHolder").expect("Could not find class!"));
+        assert!(field.get_value::<i32>(&other).is_err());
+        assert!(field.set_value::<i32>(&other,1).is_err());
+    }
+    #[test]
+    fn object_clone_and_deep_clone(){
+        use wrapped_mono::class::Class;
+        let main = jit::init("main",None);
+        let asm = main.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not find class!");
+        let field = class.get_field_from_name("someField").expect("Could not find field!");
+        let obj = Object::new(&main,&class);
+        field.set_value::<i32>(&obj,1).expect("Could not set field!");
+        let alias = obj.clone();
+        let copy = obj.deep_clone();
+        assert!(alias == obj);
+        assert!(copy != obj);
+        assert_eq!(field.get_value::<i32>(&copy),Ok(1));
+        field.set_value::<i32>(&alias,2).expect("Could not set field!");
+        assert_eq!(field.get_value::<i32>(&obj),Ok(2));
+        assert_eq!(field.get_value::<i32>(&copy),Ok(1));
+    }
+    #[test]
+    fn object_get_and_set_field_by_name(){
+        use wrapped_mono::class::Class;
+        let main = jit::init("main",None);
+        let asm = main.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not find class!");
+        let obj = Object::new(&main,&class);
+        assert!(obj.set_field::<i32>("someField",77));
+        assert_eq!(obj.get_field::<i32>("someField"),Some(77));
+        assert_eq!(obj.get_field::<i64>("someField"),None);
+        assert_eq!(obj.get_field::<i32>("missingField"),None);
+        assert!(!obj.set_field::<i32>("missingField",1));
+        assert!(!obj.set_field::<i32>("counter",1));
+    }
+    #[test]
+    fn object_call_method_by_name(){
+        let main = jit::init("main",None);
+        let asm = main.assembly_open("test/dlls/Test.dll").unwrap();
+        let obj = main.create_instance(&asm,"ValueHolder",&()).expect("Could not create object!");
+        let res = obj.call("GetValue",()).expect("Got an exception!").expect("Got null!");
+        assert_eq!(res.unbox::<i32>(),42);
+        let res = obj.call("Add",(8,)).expect("Got an exception!").expect("Got null!");
+        assert_eq!(res.unbox::<i32>(),50);
+        let derived = main.create_instance(&asm,"DerivedValueHolder",&()).expect("Could not create object!");
+        let res = derived.call("GetValue",()).expect("Got an exception!").expect("Got null!");
+        assert_eq!(res.unbox::<i32>(),42);
+        let err = obj.call("Missing",()).err().expect("Missing method was called!");
+        assert_eq!(err.class_name(),"System.MissingMethodException");
+    }
+    #[test]
+    fn object_set_field_ref(){
+        use wrapped_mono::{gc,MString,ObjectTrait};
+        let main = jit::init("main",None);
+        let asm = main.assembly_open("test/dlls/Test.dll").unwrap();
+        let holder = main.create_instance(&asm,"ReferenceHolder",&()).expect("Could not create object!");
+        let text:Object = MString::new(&main,"referenced text").cast().expect("Could not cast string to object!");
+        assert!(holder.set_field_ref("reference",&text));
+        assert!(holder.set_field_ref("text",&text));
+        // Holder is not a string, so it can't be stored in a string field.
+        assert!(!holder.set_field_ref("text",&holder));
+        assert!(!holder.set_field_ref("missingField",&text));
+        drop(text);
+        // Only the field keeps the string alive now.
+        gc::collect();
+        let field = holder.get_class().get_field_from_name("reference").expect("Could not find field!");
+        let stored:MString = field.get_value_object(&holder).expect("Reference did not survive collection!").cast().expect("Field does not hold a string!");
+        assert_eq!(stored.to_string(),"referenced text");
+    }
+    #[test]
+    fn object_get_field_object(){
+        use wrapped_mono::MString;
+        let main = jit::init("main",None);
+        let asm = main.assembly_open("test/dlls/Test.dll").unwrap();
+        let holder = main.create_instance(&asm,"ReferenceHolder",&()).expect("Could not create object!");
+        let field = holder.get_class().get_field_from_name("greeting").expect("Could not find field!");
+        let greeting:MString = field.get_reference_value(&holder).expect("Field is null").cast().expect("Field does not hold a string!");
+        assert_eq!(greeting.to_string(),"hi");
+        let greeting:MString = holder.get_field_object("greeting").expect("Field is null").cast().expect("Field does not hold a string!");
+        assert_eq!(greeting.to_string(),"hi");
+        // Fields holding null are read as `None`.
+        assert!(holder.get_field_object("text").is_none());
+        assert!(holder.get_field_object("missingField").is_none());
+        // Value type fields are not references.
+        let long_holder = main.create_instance(&asm,"LongHolder",&()).expect("Could not create object!");
+        assert!(long_holder.get_field_object("value").is_none());
+    }
+    #[test]
+    fn rooted_object_survives_collection(){
+        use wrapped_mono::{gc,MString,ObjectTrait,RootedObject};
+        struct Holder{
+            rooted:RootedObject,
+        }
+        let main = jit::init("main",None);
+        let text:Object = MString::new(&main,"rooted text").cast().expect("Could not cast string to object!");
+        let hash = text.hash();
+        let holder = Holder{rooted:text.rooted()};
+        // Allocate garbage, so the collection has something to collect and move.
+        for i in 0..1000{
+            let _ = MString::new(&main,&format!("garbage {i}"));
+        }
+        gc::collect();
+        gc::collect();
+        let stored:MString = holder.rooted.cast().expect("Rooted object is not a string!");
+        assert_eq!(stored.to_string(),"rooted text");
+        assert_eq!(holder.rooted.hash(),hash);
+        assert!(holder.rooted.get() == holder.rooted);
+    }
+    #[test]
+    fn object_size_of_string(){
+        use wrapped_mono::{MString,ObjectTrait};
+        let main = jit::init("main",None);
+        let short = MString::new(&main,"abcd");
+        let long = MString::new(&main,"abcdabcdabcdabcd");
+        let header = std::mem::size_of::<wrapped_mono::binds::MonoObject>() as u32;
+        assert!(short.get_size() > header);
+        // Strings are stored as UTF-16, so each additional character takes 2 bytes.
+        assert_eq!(long.get_size() - short.get_size(),12 * 2);
+    }
+    #[test]
+    fn object_hash_set(){
+        use std::collections::HashSet;
+        let main = jit::init("main",None);
+        let obj = Object::box_val::<i32>(&main,5);
+        let same_value = Object::box_val::<i32>(&main,5);
+        let mut set = HashSet::new();
+        set.insert(obj.clone());
+        assert!(set.contains(&obj));
+        // Objects are compared by reference, so a distinct object holding the same value is not found.
+        assert!(!set.contains(&same_value));
+        assert!(!set.insert(obj.clone()));
+        assert!(set.insert(same_value));
+        assert_eq!(set.len(),2);
+    }
+    #[test]
+    fn object_get_domain(){
+        use wrapped_mono::{Domain,MString};
+        let main = jit::init("main",None);
+        let obj = Object::box_val::<i32>(&main,5);
+        assert!(obj.get_domain() == main);
+        // Allocating in the domain of an object places new objects next to it.
+        let text = MString::new(&obj.get_domain(),"text");
+        assert!(text.get_domain() == main);
+        let other = Domain::create();
+        let other_obj = Object::box_val::<i32>(&other,5);
+        assert!(other_obj.get_domain() == other);
+        assert!(other_obj.get_domain() != main);
+    }
 }
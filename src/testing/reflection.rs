@@ -25,6 +25,39 @@ rusty_fork_test! {
         let class = Class::from_name(&img,"System","Tuple`2").expect("Could not get class");
         let _rftype = ReflectionType::from_class(&class);
     }
+    #[test]
+    fn class_generic_argument_count(){
+        let _dom = jit::init("root",None);
+        let img = Assembly::assembly_loaded("mscorlib").unwrap().get_image();
+        let dictionary = Class::from_name(&img,"System.Collections.Generic","Dictionary`2").expect("Could not get class");
+        assert!(dictionary.generic_argument_count() == 2);
+        let list_type = ReflectionType::from_name("System.Collections.Generic.List`1[System.Int32]",img).expect("Could not get type");
+        let list = unsafe{Class::from_ptr(binds::mono_class_from_mono_type(list_type.get_type_ptr()))}.expect("Could not get class");
+        assert!(list.generic_argument_count() == 1);
+        assert!(Class::get_int_32().generic_argument_count() == 0);
+    }
+    #[test]
+    fn class_generic_param_count(){
+        let _dom = jit::init("root",None);
+        let img = Assembly::assembly_loaded("mscorlib").unwrap().get_image();
+        let list = Class::from_name(&img,"System.Collections.Generic","List`1").expect("Could not get class");
+        assert_eq!(list.generic_param_count(),1);
+        let dictionary = Class::from_name(&img,"System.Collections.Generic","Dictionary`2").expect("Could not get class");
+        assert_eq!(dictionary.generic_param_count(),2);
+        assert_eq!(Class::get_string().generic_param_count(),0);
+        // Closed instantiations have no parameters left to bind.
+        let int_list = list.bind_generic_params(&[Class::get_int_32()]).expect("Could not bind generic parameters");
+        assert_eq!(int_list.generic_param_count(),0);
+        assert!(int_list.bind_generic_params(&[Class::get_int_32()]).is_none());
+    }
+    #[test]
+    fn method_generic_argument_count(){
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").unwrap();
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not get class");
+        let met:Method<(i32,)> = Method::get_from_name(&class,"GetArg",1).expect("Could not get method");
+        assert!(met.generic_argument_count() == 0);
+    }
     // TODO:re-enable this test on the dev branch when working on 0.3(it is not planed to be in 0.2) and try to fix the bug that prevents it from working: Check if the type we get is realy generic (maybe types such as "System.Tuple`3" are cast to "System.Tuple" behind the scenes?
     /*
     #[test]
@@ -38,4 +71,24 @@ rusty_fork_test! {
             &[Class::get_int_32().into(),Class::get_byte().into(),Class::get_sbyte().into()]
         ).unwrap();
     }*/
+    #[test]
+    fn bind_generic_params(){
+        use wrapped_mono::*;
+        let _domain = jit::init("main",None);
+        let img = Assembly::assembly_loaded("mscorlib").expect("Could not get mscorlib").get_image();
+        let list = Class::from_name(&img,"System.Collections.Generic","List`1").expect("Could not get class");
+        let int_list = list.bind_generic_params(&[Class::get_int_32()]).expect("Could not bind generic parameters");
+        assert_eq!(int_list.get_name(),"List`1");
+        assert_eq!(int_list.get_type().get_name(),"System.Collections.Generic.List<System.Int32>");
+        assert_eq!(int_list.get_type().get_name_full(TypeNameFormat::Reflection),"System.Collections.Generic.List`1[System.Int32]");
+        assert_eq!(int_list.generic_argument_count(),1);
+        assert!(list.bind_generic_params(&[Class::get_int_32(),Class::get_int_32()]).is_none());
+    }
+    #[test]
+    fn class_reflection_type(){
+        let dom = jit::init("root",None);
+        let string_type = Class::get_string().get_reflection_type(&dom);
+        let full_name:MString = string_type.call("get_FullName",()).expect("Got an exception").expect("Got null").cast().expect("FullName is not a string");
+        assert_eq!(full_name.to_string(),"System.String");
+    }
 }
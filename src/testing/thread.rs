@@ -0,0 +1,34 @@
+use crate::*;
+use rusty_fork::rusty_fork_test;
+rusty_fork_test! {
+    #[test]
+    fn managed_thread_ids_differ(){
+        let dom = jit::init("root",None);
+        let main_id = thread::current_managed_thread_id();
+        let other_id = std::thread::scope(|s|{
+            s.spawn(||{
+                dom.attach_thread();
+                thread::current_managed_thread_id()
+            }).join().expect("Attached thread panicked!")
+        });
+        assert!(main_id != other_id,"{} == {}",main_id,other_id);
+        assert!(main_id == thread::current_managed_thread_id());
+    }
+    #[test]
+    fn attach_detach_thread(){
+        let dom = jit::init("root",None);
+        let asm = dom.assembly_open("test/dlls/Test.dll").expect("Could not load assembly");
+        let class = Class::from_name(&asm.get_image(),"","TestFunctions").expect("Could not find class");
+        let res = std::thread::scope(|s|{
+            s.spawn(||{
+                let handle = thread::attach(&dom);
+                assert!(!handle.get_ptr().is_null());
+                let met:Method<()> = Method::get_from_name(&class,"GetOne",0).expect("Could not find method");
+                let res = met.invoke(None,()).expect("Got an exception").expect("Got null").unbox::<i32>();
+                drop(handle);
+                res
+            }).join().expect("Attached thread panicked!")
+        });
+        assert_eq!(res,1);
+    }
+}
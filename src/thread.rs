@@ -0,0 +1,77 @@
+use crate::binds::MonoThread;
+use crate::{Class, Domain, Method};
+use std::sync::LazyLock;
+/// Handle representing a native thread attached to the runtime with [`attach`]. When dropped, detaches the thread it was created on.
+/// Can't be sent to other threads, since it must be dropped on the thread it represents.
+pub struct ThreadHandle {
+    thread: *mut MonoThread,
+}
+impl ThreadHandle {
+    /// Returns internal pointer to [`MonoThread`] representing the attached thread.
+    #[must_use]
+    pub fn get_ptr(&self) -> *mut MonoThread {
+        self.thread
+    }
+}
+impl Drop for ThreadHandle {
+    fn drop(&mut self) {
+        unsafe { crate::binds::mono_thread_detach(self.thread) };
+    }
+}
+/// Attaches current native thread to the runtime, making it safe to call managed code from it.
+/// Must be called on threads not created by the runtime before they interact with *domain* in any way, since the garbage collector needs to be aware of them.
+/// The thread stays attached until returned [`ThreadHandle`] is dropped. Should not be used on threads the runtime already knows about(e.g. the thread that initialized the JIT),
+/// since dropping the handle would detach them.
+/// # Example
+/// ```no_run
+/// # use wrapped_mono::*;
+/// # let domain = jit::init("name",None);
+/// std::thread::scope(|s|{
+///     s.spawn(||{
+///         let _handle = thread::attach(&domain);
+///         // Managed code can be safely called here.
+///     });
+/// });
+/// ```
+/// # Panics
+/// Panics if the thread could not be attached.
+#[must_use]
+pub fn attach(domain: &Domain) -> ThreadHandle {
+    let thread = unsafe { crate::binds::mono_thread_attach(domain.get_ptr()) };
+    assert!(!thread.is_null(), "Could not attach thread to domain!");
+    ThreadHandle { thread }
+}
+/// Returns `Thread.CurrentThread.ManagedThreadId` of the calling thread. Useful for correlating managed logs and exceptions with native threads.
+/// Current thread must be attached to a domain(see [`attach`] and [`crate::Domain::attach_thread`]).
+/// # Example
+/// ```no_run
+/// # use wrapped_mono::*;
+/// # let domain = jit::init("name",None);
+/// println!("Running on managed thread {}",thread::current_managed_thread_id());
+/// ```
+/// # Panics
+/// Panics if `System.Threading.Thread` getters could not be found or threw an exception.
+#[must_use]
+pub fn current_managed_thread_id() -> i32 {
+    let class = *THREAD_CLASS;
+    let get_current: Method<()> = Method::get_from_name(&class, "get_CurrentThread", 0)
+        .expect("Could not find Thread.CurrentThread getter!");
+    let thread = get_current
+        .invoke(None, ())
+        .expect("Got an exception while getting Thread.CurrentThread!")
+        .expect("Thread.CurrentThread returned null!");
+    let get_id: Method<()> = Method::get_from_name(&class, "get_ManagedThreadId", 0)
+        .expect("Could not find Thread.ManagedThreadId getter!");
+    get_id
+        .invoke(Some(thread), ())
+        .expect("Got an exception while getting Thread.ManagedThreadId!")
+        .expect("Thread.ManagedThreadId returned null!")
+        .unbox::<i32>()
+}
+static THREAD_CLASS: LazyLock<Class> = LazyLock::new(|| {
+    let img = crate::Assembly::assembly_loaded("mscorlib")
+        .expect("Assembly mscorlib not loaded, could not get System.Threading.Thread class!")
+        .get_image();
+    Class::from_name_case(&img, "System.Threading", "Thread")
+        .expect("Could not get System.Threading.Thread class form mscorlib!")
+});
@@ -2,36 +2,45 @@
 use core::ffi::c_void;
 //for argument processing
 type VoidPtr = *mut c_void;
+// Converts *arg* to a pointer passed to the runtime. Objects created while converting it(e.g. managed strings or boxed values) are referenced only by the returned pointer,
+// so they are added to *roots*, which must be kept until the call returns.
+fn send_ptr<T: InteropSend>(arg: &mut T, roots: &mut Vec<RootedObject>) -> *mut c_void {
+    let ptr = arg.get_ffi_ptr();
+    if T::is_object_arg() {
+        roots.extend(unsafe { RootedObject::from_ptr(ptr.cast()) });
+    }
+    ptr
+}
 //Conversion of a tuple to pointers
 pub trait TupleToFFIPtrs {
-    type PTRS;
-    fn get_ptrs(&mut self) -> Self::PTRS;
+    type PTRS: AsMut<[*mut c_void]>;
+    fn get_ptrs(&mut self, roots: &mut Vec<RootedObject>) -> Self::PTRS;
 }
 impl TupleToFFIPtrs for () {
     type PTRS = [*mut c_void; 0];
-    fn get_ptrs(&mut self) -> Self::PTRS {
+    fn get_ptrs(&mut self, _roots: &mut Vec<RootedObject>) -> Self::PTRS {
         []
     }
 }
 impl<A: InteropSend> TupleToFFIPtrs for (A,) {
     type PTRS = [*mut c_void; 1];
-    fn get_ptrs(&mut self) -> Self::PTRS {
-        [self.0.get_ffi_ptr()]
+    fn get_ptrs(&mut self, roots: &mut Vec<RootedObject>) -> Self::PTRS {
+        [send_ptr(&mut self.0, roots)]
     }
 }
 impl<A: InteropSend, B: InteropSend> TupleToFFIPtrs for (A, B) {
     type PTRS = [*mut c_void; 2];
-    fn get_ptrs(&mut self) -> Self::PTRS {
-        [self.0.get_ffi_ptr(), self.1.get_ffi_ptr()]
+    fn get_ptrs(&mut self, roots: &mut Vec<RootedObject>) -> Self::PTRS {
+        [send_ptr(&mut self.0, roots), send_ptr(&mut self.1, roots)]
     }
 }
 impl<A: InteropSend, B: InteropSend, C: InteropSend> TupleToFFIPtrs for (A, B, C) {
     type PTRS = [*mut c_void; 3];
-    fn get_ptrs(&mut self) -> Self::PTRS {
+    fn get_ptrs(&mut self, roots: &mut Vec<RootedObject>) -> Self::PTRS {
         [
-            self.0.get_ffi_ptr(),
-            self.1.get_ffi_ptr(),
-            self.2.get_ffi_ptr(),
+            send_ptr(&mut self.0, roots),
+            send_ptr(&mut self.1, roots),
+            send_ptr(&mut self.2, roots),
         ]
     }
 }
@@ -39,12 +48,12 @@ impl<A: InteropSend, B: InteropSend, C: InteropSend, D: InteropSend> TupleToFFIP
     for (A, B, C, D)
 {
     type PTRS = [*mut c_void; 4];
-    fn get_ptrs(&mut self) -> Self::PTRS {
+    fn get_ptrs(&mut self, roots: &mut Vec<RootedObject>) -> Self::PTRS {
         [
-            self.0.get_ffi_ptr(),
-            self.1.get_ffi_ptr(),
-            self.2.get_ffi_ptr(),
-            self.3.get_ffi_ptr(),
+            send_ptr(&mut self.0, roots),
+            send_ptr(&mut self.1, roots),
+            send_ptr(&mut self.2, roots),
+            send_ptr(&mut self.3, roots),
         ]
     }
 }
@@ -52,13 +61,13 @@ impl<A: InteropSend, B: InteropSend, C: InteropSend, D: InteropSend, E: InteropS
     for (A, B, C, D, E)
 {
     type PTRS = [*mut c_void; 5];
-    fn get_ptrs(&mut self) -> Self::PTRS {
+    fn get_ptrs(&mut self, roots: &mut Vec<RootedObject>) -> Self::PTRS {
         [
-            self.0.get_ffi_ptr(),
-            self.1.get_ffi_ptr(),
-            self.2.get_ffi_ptr(),
-            self.3.get_ffi_ptr(),
-            self.4.get_ffi_ptr(),
+            send_ptr(&mut self.0, roots),
+            send_ptr(&mut self.1, roots),
+            send_ptr(&mut self.2, roots),
+            send_ptr(&mut self.3, roots),
+            send_ptr(&mut self.4, roots),
         ]
     }
 }
@@ -72,14 +81,14 @@ impl<
     > TupleToFFIPtrs for (A, B, C, D, E, F)
 {
     type PTRS = [*mut c_void; 6];
-    fn get_ptrs(&mut self) -> Self::PTRS {
+    fn get_ptrs(&mut self, roots: &mut Vec<RootedObject>) -> Self::PTRS {
         [
-            self.0.get_ffi_ptr(),
-            self.1.get_ffi_ptr(),
-            self.2.get_ffi_ptr(),
-            self.3.get_ffi_ptr(),
-            self.4.get_ffi_ptr(),
-            self.5.get_ffi_ptr(),
+            send_ptr(&mut self.0, roots),
+            send_ptr(&mut self.1, roots),
+            send_ptr(&mut self.2, roots),
+            send_ptr(&mut self.3, roots),
+            send_ptr(&mut self.4, roots),
+            send_ptr(&mut self.5, roots),
         ]
     }
 }
@@ -94,15 +103,15 @@ impl<
     > TupleToFFIPtrs for (A, B, C, D, E, F, G)
 {
     type PTRS = [*mut c_void; 7];
-    fn get_ptrs(&mut self) -> Self::PTRS {
+    fn get_ptrs(&mut self, roots: &mut Vec<RootedObject>) -> Self::PTRS {
         [
-            self.0.get_ffi_ptr(),
-            self.1.get_ffi_ptr(),
-            self.2.get_ffi_ptr(),
-            self.3.get_ffi_ptr(),
-            self.4.get_ffi_ptr(),
-            self.5.get_ffi_ptr(),
-            self.6.get_ffi_ptr(),
+            send_ptr(&mut self.0, roots),
+            send_ptr(&mut self.1, roots),
+            send_ptr(&mut self.2, roots),
+            send_ptr(&mut self.3, roots),
+            send_ptr(&mut self.4, roots),
+            send_ptr(&mut self.5, roots),
+            send_ptr(&mut self.6, roots),
         ]
     }
 }
@@ -118,16 +127,16 @@ impl<
     > TupleToFFIPtrs for (A, B, C, D, E, F, G, H)
 {
     type PTRS = [*mut c_void; 8];
-    fn get_ptrs(&mut self) -> Self::PTRS {
+    fn get_ptrs(&mut self, roots: &mut Vec<RootedObject>) -> Self::PTRS {
         [
-            self.0.get_ffi_ptr(),
-            self.1.get_ffi_ptr(),
-            self.2.get_ffi_ptr(),
-            self.3.get_ffi_ptr(),
-            self.4.get_ffi_ptr(),
-            self.5.get_ffi_ptr(),
-            self.6.get_ffi_ptr(),
-            self.7.get_ffi_ptr(),
+            send_ptr(&mut self.0, roots),
+            send_ptr(&mut self.1, roots),
+            send_ptr(&mut self.2, roots),
+            send_ptr(&mut self.3, roots),
+            send_ptr(&mut self.4, roots),
+            send_ptr(&mut self.5, roots),
+            send_ptr(&mut self.6, roots),
+            send_ptr(&mut self.7, roots),
         ]
     }
 }
@@ -144,17 +153,17 @@ impl<
     > TupleToFFIPtrs for (A, B, C, D, E, F, G, H, I)
 {
     type PTRS = [*mut c_void; 9];
-    fn get_ptrs(&mut self) -> Self::PTRS {
+    fn get_ptrs(&mut self, roots: &mut Vec<RootedObject>) -> Self::PTRS {
         [
-            self.0.get_ffi_ptr(),
-            self.1.get_ffi_ptr(),
-            self.2.get_ffi_ptr(),
-            self.3.get_ffi_ptr(),
-            self.4.get_ffi_ptr(),
-            self.5.get_ffi_ptr(),
-            self.6.get_ffi_ptr(),
-            self.7.get_ffi_ptr(),
-            self.8.get_ffi_ptr(),
+            send_ptr(&mut self.0, roots),
+            send_ptr(&mut self.1, roots),
+            send_ptr(&mut self.2, roots),
+            send_ptr(&mut self.3, roots),
+            send_ptr(&mut self.4, roots),
+            send_ptr(&mut self.5, roots),
+            send_ptr(&mut self.6, roots),
+            send_ptr(&mut self.7, roots),
+            send_ptr(&mut self.8, roots),
         ]
     }
 }
@@ -172,18 +181,18 @@ impl<
     > TupleToFFIPtrs for (A, B, C, D, E, F, G, H, I, J)
 {
     type PTRS = [*mut c_void; 10];
-    fn get_ptrs(&mut self) -> Self::PTRS {
+    fn get_ptrs(&mut self, roots: &mut Vec<RootedObject>) -> Self::PTRS {
         [
-            self.0.get_ffi_ptr(),
-            self.1.get_ffi_ptr(),
-            self.2.get_ffi_ptr(),
-            self.3.get_ffi_ptr(),
-            self.4.get_ffi_ptr(),
-            self.5.get_ffi_ptr(),
-            self.6.get_ffi_ptr(),
-            self.7.get_ffi_ptr(),
-            self.8.get_ffi_ptr(),
-            self.9.get_ffi_ptr(),
+            send_ptr(&mut self.0, roots),
+            send_ptr(&mut self.1, roots),
+            send_ptr(&mut self.2, roots),
+            send_ptr(&mut self.3, roots),
+            send_ptr(&mut self.4, roots),
+            send_ptr(&mut self.5, roots),
+            send_ptr(&mut self.6, roots),
+            send_ptr(&mut self.7, roots),
+            send_ptr(&mut self.8, roots),
+            send_ptr(&mut self.9, roots),
         ]
     }
 }
@@ -202,19 +211,19 @@ impl<
     > TupleToFFIPtrs for (A, B, C, D, E, F, G, H, I, J, K)
 {
     type PTRS = [*mut c_void; 11];
-    fn get_ptrs(&mut self) -> Self::PTRS {
+    fn get_ptrs(&mut self, roots: &mut Vec<RootedObject>) -> Self::PTRS {
         [
-            self.0.get_ffi_ptr(),
-            self.1.get_ffi_ptr(),
-            self.2.get_ffi_ptr(),
-            self.3.get_ffi_ptr(),
-            self.4.get_ffi_ptr(),
-            self.5.get_ffi_ptr(),
-            self.6.get_ffi_ptr(),
-            self.7.get_ffi_ptr(),
-            self.8.get_ffi_ptr(),
-            self.9.get_ffi_ptr(),
-            self.10.get_ffi_ptr(),
+            send_ptr(&mut self.0, roots),
+            send_ptr(&mut self.1, roots),
+            send_ptr(&mut self.2, roots),
+            send_ptr(&mut self.3, roots),
+            send_ptr(&mut self.4, roots),
+            send_ptr(&mut self.5, roots),
+            send_ptr(&mut self.6, roots),
+            send_ptr(&mut self.7, roots),
+            send_ptr(&mut self.8, roots),
+            send_ptr(&mut self.9, roots),
+            send_ptr(&mut self.10, roots),
         ]
     }
 }
@@ -234,20 +243,20 @@ impl<
     > TupleToFFIPtrs for (A, B, C, D, E, F, G, H, I, J, K, L)
 {
     type PTRS = [*mut c_void; 12];
-    fn get_ptrs(&mut self) -> Self::PTRS {
+    fn get_ptrs(&mut self, roots: &mut Vec<RootedObject>) -> Self::PTRS {
         [
-            self.0.get_ffi_ptr(),
-            self.1.get_ffi_ptr(),
-            self.2.get_ffi_ptr(),
-            self.3.get_ffi_ptr(),
-            self.4.get_ffi_ptr(),
-            self.5.get_ffi_ptr(),
-            self.6.get_ffi_ptr(),
-            self.7.get_ffi_ptr(),
-            self.8.get_ffi_ptr(),
-            self.9.get_ffi_ptr(),
-            self.10.get_ffi_ptr(),
-            self.11.get_ffi_ptr(),
+            send_ptr(&mut self.0, roots),
+            send_ptr(&mut self.1, roots),
+            send_ptr(&mut self.2, roots),
+            send_ptr(&mut self.3, roots),
+            send_ptr(&mut self.4, roots),
+            send_ptr(&mut self.5, roots),
+            send_ptr(&mut self.6, roots),
+            send_ptr(&mut self.7, roots),
+            send_ptr(&mut self.8, roots),
+            send_ptr(&mut self.9, roots),
+            send_ptr(&mut self.10, roots),
+            send_ptr(&mut self.11, roots),
         ]
     }
 }
@@ -268,21 +277,21 @@ impl<
     > TupleToFFIPtrs for (A, B, C, D, E, F, G, H, I, J, K, L, M)
 {
     type PTRS = [*mut c_void; 13];
-    fn get_ptrs(&mut self) -> Self::PTRS {
+    fn get_ptrs(&mut self, roots: &mut Vec<RootedObject>) -> Self::PTRS {
         [
-            self.0.get_ffi_ptr(),
-            self.1.get_ffi_ptr(),
-            self.2.get_ffi_ptr(),
-            self.3.get_ffi_ptr(),
-            self.4.get_ffi_ptr(),
-            self.5.get_ffi_ptr(),
-            self.6.get_ffi_ptr(),
-            self.7.get_ffi_ptr(),
-            self.8.get_ffi_ptr(),
-            self.9.get_ffi_ptr(),
-            self.10.get_ffi_ptr(),
-            self.11.get_ffi_ptr(),
-            self.12.get_ffi_ptr(),
+            send_ptr(&mut self.0, roots),
+            send_ptr(&mut self.1, roots),
+            send_ptr(&mut self.2, roots),
+            send_ptr(&mut self.3, roots),
+            send_ptr(&mut self.4, roots),
+            send_ptr(&mut self.5, roots),
+            send_ptr(&mut self.6, roots),
+            send_ptr(&mut self.7, roots),
+            send_ptr(&mut self.8, roots),
+            send_ptr(&mut self.9, roots),
+            send_ptr(&mut self.10, roots),
+            send_ptr(&mut self.11, roots),
+            send_ptr(&mut self.12, roots),
         ]
     }
 }
@@ -304,22 +313,22 @@ impl<
     > TupleToFFIPtrs for (A, B, C, D, E, F, G, H, I, J, K, L, M, N)
 {
     type PTRS = [*mut c_void; 14];
-    fn get_ptrs(&mut self) -> Self::PTRS {
+    fn get_ptrs(&mut self, roots: &mut Vec<RootedObject>) -> Self::PTRS {
         [
-            self.0.get_ffi_ptr(),
-            self.1.get_ffi_ptr(),
-            self.2.get_ffi_ptr(),
-            self.3.get_ffi_ptr(),
-            self.4.get_ffi_ptr(),
-            self.5.get_ffi_ptr(),
-            self.6.get_ffi_ptr(),
-            self.7.get_ffi_ptr(),
-            self.8.get_ffi_ptr(),
-            self.9.get_ffi_ptr(),
-            self.10.get_ffi_ptr(),
-            self.11.get_ffi_ptr(),
-            self.12.get_ffi_ptr(),
-            self.13.get_ffi_ptr(),
+            send_ptr(&mut self.0, roots),
+            send_ptr(&mut self.1, roots),
+            send_ptr(&mut self.2, roots),
+            send_ptr(&mut self.3, roots),
+            send_ptr(&mut self.4, roots),
+            send_ptr(&mut self.5, roots),
+            send_ptr(&mut self.6, roots),
+            send_ptr(&mut self.7, roots),
+            send_ptr(&mut self.8, roots),
+            send_ptr(&mut self.9, roots),
+            send_ptr(&mut self.10, roots),
+            send_ptr(&mut self.11, roots),
+            send_ptr(&mut self.12, roots),
+            send_ptr(&mut self.13, roots),
         ]
     }
 }
@@ -342,23 +351,23 @@ impl<
     > TupleToFFIPtrs for (A, B, C, D, E, F, G, H, I, J, K, L, M, N, O)
 {
     type PTRS = [*mut c_void; 15];
-    fn get_ptrs(&mut self) -> Self::PTRS {
+    fn get_ptrs(&mut self, roots: &mut Vec<RootedObject>) -> Self::PTRS {
         [
-            self.0.get_ffi_ptr(),
-            self.1.get_ffi_ptr(),
-            self.2.get_ffi_ptr(),
-            self.3.get_ffi_ptr(),
-            self.4.get_ffi_ptr(),
-            self.5.get_ffi_ptr(),
-            self.6.get_ffi_ptr(),
-            self.7.get_ffi_ptr(),
-            self.8.get_ffi_ptr(),
-            self.9.get_ffi_ptr(),
-            self.10.get_ffi_ptr(),
-            self.11.get_ffi_ptr(),
-            self.12.get_ffi_ptr(),
-            self.13.get_ffi_ptr(),
-            self.14.get_ffi_ptr(),
+            send_ptr(&mut self.0, roots),
+            send_ptr(&mut self.1, roots),
+            send_ptr(&mut self.2, roots),
+            send_ptr(&mut self.3, roots),
+            send_ptr(&mut self.4, roots),
+            send_ptr(&mut self.5, roots),
+            send_ptr(&mut self.6, roots),
+            send_ptr(&mut self.7, roots),
+            send_ptr(&mut self.8, roots),
+            send_ptr(&mut self.9, roots),
+            send_ptr(&mut self.10, roots),
+            send_ptr(&mut self.11, roots),
+            send_ptr(&mut self.12, roots),
+            send_ptr(&mut self.13, roots),
+            send_ptr(&mut self.14, roots),
         ]
     }
 }
@@ -382,30 +391,34 @@ impl<
     > TupleToFFIPtrs for (A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P)
 {
     type PTRS = [*mut c_void; 16];
-    fn get_ptrs(&mut self) -> Self::PTRS {
+    fn get_ptrs(&mut self, roots: &mut Vec<RootedObject>) -> Self::PTRS {
         [
-            self.0.get_ffi_ptr(),
-            self.1.get_ffi_ptr(),
-            self.2.get_ffi_ptr(),
-            self.3.get_ffi_ptr(),
-            self.4.get_ffi_ptr(),
-            self.5.get_ffi_ptr(),
-            self.6.get_ffi_ptr(),
-            self.7.get_ffi_ptr(),
-            self.8.get_ffi_ptr(),
-            self.9.get_ffi_ptr(),
-            self.10.get_ffi_ptr(),
-            self.11.get_ffi_ptr(),
-            self.12.get_ffi_ptr(),
-            self.13.get_ffi_ptr(),
-            self.14.get_ffi_ptr(),
-            self.15.get_ffi_ptr(),
+            send_ptr(&mut self.0, roots),
+            send_ptr(&mut self.1, roots),
+            send_ptr(&mut self.2, roots),
+            send_ptr(&mut self.3, roots),
+            send_ptr(&mut self.4, roots),
+            send_ptr(&mut self.5, roots),
+            send_ptr(&mut self.6, roots),
+            send_ptr(&mut self.7, roots),
+            send_ptr(&mut self.8, roots),
+            send_ptr(&mut self.9, roots),
+            send_ptr(&mut self.10, roots),
+            send_ptr(&mut self.11, roots),
+            send_ptr(&mut self.12, roots),
+            send_ptr(&mut self.13, roots),
+            send_ptr(&mut self.14, roots),
+            send_ptr(&mut self.15, roots),
         ]
     }
 }
-use crate::{Class, InteropClass, InteropSend};
+use crate::{Class, InteropClass, InteropSend, ObjectTrait, RootedObject};
 pub trait CompareClasses {
     fn compare(classes: &[Class]) -> bool;
+    /// Checks if arguments passed by reference(see [`InteropClass::is_passed_by_ref`]) are passed only to parameters passed by reference. *byref* holds this flag for each parameter.
+    fn compare_byref(_byref: &[bool]) -> bool {
+        true
+    }
 }
 impl CompareClasses for () {
     fn compare(classes: &[Class]) -> bool {
@@ -416,6 +429,9 @@ impl<A: InteropClass> CompareClasses for (A,) {
     fn compare(classes: &[Class]) -> bool {
         (classes.len() == 1) && (A::get_mono_class().is_assignable_from(&classes[0]))
     }
+    fn compare_byref(byref: &[bool]) -> bool {
+        !A::is_passed_by_ref() || byref[0]
+    }
 }
 impl<A: InteropClass, B: InteropClass> CompareClasses for (A, B)
 where
@@ -426,6 +442,9 @@ where
             && (A::get_mono_class().is_assignable_from(&classes[0]))
             && (B::get_mono_class().is_assignable_from(&classes[1]))
     }
+    fn compare_byref(byref: &[bool]) -> bool {
+        (!A::is_passed_by_ref() || byref[0]) && (!B::is_passed_by_ref() || byref[1])
+    }
 }
 impl<A: InteropClass, B: InteropClass, C: InteropClass> CompareClasses for (A, B, C)
 where
@@ -437,6 +456,11 @@ where
             && (B::get_mono_class().is_assignable_from(&classes[1]))
             && (C::get_mono_class().is_assignable_from(&classes[2]))
     }
+    fn compare_byref(byref: &[bool]) -> bool {
+        (!A::is_passed_by_ref() || byref[0])
+            && (!B::is_passed_by_ref() || byref[1])
+            && (!C::is_passed_by_ref() || byref[2])
+    }
 }
 impl<A: InteropClass, B: InteropClass, C: InteropClass, D: InteropClass> CompareClasses
     for (A, B, C, D)
@@ -450,6 +474,12 @@ where
             && (C::get_mono_class().is_assignable_from(&classes[2]))
             && (D::get_mono_class().is_assignable_from(&classes[3]))
     }
+    fn compare_byref(byref: &[bool]) -> bool {
+        (!A::is_passed_by_ref() || byref[0])
+            && (!B::is_passed_by_ref() || byref[1])
+            && (!C::is_passed_by_ref() || byref[2])
+            && (!D::is_passed_by_ref() || byref[3])
+    }
 }
 impl<A: InteropClass, B: InteropClass, C: InteropClass, D: InteropClass, E: InteropClass>
     CompareClasses for (A, B, C, D, E)
@@ -464,6 +494,13 @@ where
             && (D::get_mono_class().is_assignable_from(&classes[3]))
             && (E::get_mono_class().is_assignable_from(&classes[4]))
     }
+    fn compare_byref(byref: &[bool]) -> bool {
+        (!A::is_passed_by_ref() || byref[0])
+            && (!B::is_passed_by_ref() || byref[1])
+            && (!C::is_passed_by_ref() || byref[2])
+            && (!D::is_passed_by_ref() || byref[3])
+            && (!E::is_passed_by_ref() || byref[4])
+    }
 }
 impl<
         A: InteropClass,
@@ -485,6 +522,14 @@ where
             && (E::get_mono_class().is_assignable_from(&classes[4]))
             && (F::get_mono_class().is_assignable_from(&classes[5]))
     }
+    fn compare_byref(byref: &[bool]) -> bool {
+        (!A::is_passed_by_ref() || byref[0])
+            && (!B::is_passed_by_ref() || byref[1])
+            && (!C::is_passed_by_ref() || byref[2])
+            && (!D::is_passed_by_ref() || byref[3])
+            && (!E::is_passed_by_ref() || byref[4])
+            && (!F::is_passed_by_ref() || byref[5])
+    }
 }
 impl<
         A: InteropClass,
@@ -508,6 +553,15 @@ where
             && (F::get_mono_class().is_assignable_from(&classes[5]))
             && (G::get_mono_class().is_assignable_from(&classes[6]))
     }
+    fn compare_byref(byref: &[bool]) -> bool {
+        (!A::is_passed_by_ref() || byref[0])
+            && (!B::is_passed_by_ref() || byref[1])
+            && (!C::is_passed_by_ref() || byref[2])
+            && (!D::is_passed_by_ref() || byref[3])
+            && (!E::is_passed_by_ref() || byref[4])
+            && (!F::is_passed_by_ref() || byref[5])
+            && (!G::is_passed_by_ref() || byref[6])
+    }
 }
 impl<
         A: InteropClass,
@@ -533,6 +587,16 @@ where
             && (G::get_mono_class().is_assignable_from(&classes[6]))
             && (H::get_mono_class().is_assignable_from(&classes[7]))
     }
+    fn compare_byref(byref: &[bool]) -> bool {
+        (!A::is_passed_by_ref() || byref[0])
+            && (!B::is_passed_by_ref() || byref[1])
+            && (!C::is_passed_by_ref() || byref[2])
+            && (!D::is_passed_by_ref() || byref[3])
+            && (!E::is_passed_by_ref() || byref[4])
+            && (!F::is_passed_by_ref() || byref[5])
+            && (!G::is_passed_by_ref() || byref[6])
+            && (!H::is_passed_by_ref() || byref[7])
+    }
 }
 impl<
         A: InteropClass,
@@ -560,6 +624,17 @@ where
             && (H::get_mono_class().is_assignable_from(&classes[7]))
             && (I::get_mono_class().is_assignable_from(&classes[8]))
     }
+    fn compare_byref(byref: &[bool]) -> bool {
+        (!A::is_passed_by_ref() || byref[0])
+            && (!B::is_passed_by_ref() || byref[1])
+            && (!C::is_passed_by_ref() || byref[2])
+            && (!D::is_passed_by_ref() || byref[3])
+            && (!E::is_passed_by_ref() || byref[4])
+            && (!F::is_passed_by_ref() || byref[5])
+            && (!G::is_passed_by_ref() || byref[6])
+            && (!H::is_passed_by_ref() || byref[7])
+            && (!I::is_passed_by_ref() || byref[8])
+    }
 }
 impl<
         A: InteropClass,
@@ -589,6 +664,18 @@ where
             && (I::get_mono_class().is_assignable_from(&classes[8]))
             && (J::get_mono_class().is_assignable_from(&classes[9]))
     }
+    fn compare_byref(byref: &[bool]) -> bool {
+        (!A::is_passed_by_ref() || byref[0])
+            && (!B::is_passed_by_ref() || byref[1])
+            && (!C::is_passed_by_ref() || byref[2])
+            && (!D::is_passed_by_ref() || byref[3])
+            && (!E::is_passed_by_ref() || byref[4])
+            && (!F::is_passed_by_ref() || byref[5])
+            && (!G::is_passed_by_ref() || byref[6])
+            && (!H::is_passed_by_ref() || byref[7])
+            && (!I::is_passed_by_ref() || byref[8])
+            && (!J::is_passed_by_ref() || byref[9])
+    }
 }
 impl<
         A: InteropClass,
@@ -620,6 +707,19 @@ where
             && (J::get_mono_class().is_assignable_from(&classes[9]))
             && (K::get_mono_class().is_assignable_from(&classes[10]))
     }
+    fn compare_byref(byref: &[bool]) -> bool {
+        (!A::is_passed_by_ref() || byref[0])
+            && (!B::is_passed_by_ref() || byref[1])
+            && (!C::is_passed_by_ref() || byref[2])
+            && (!D::is_passed_by_ref() || byref[3])
+            && (!E::is_passed_by_ref() || byref[4])
+            && (!F::is_passed_by_ref() || byref[5])
+            && (!G::is_passed_by_ref() || byref[6])
+            && (!H::is_passed_by_ref() || byref[7])
+            && (!I::is_passed_by_ref() || byref[8])
+            && (!J::is_passed_by_ref() || byref[9])
+            && (!K::is_passed_by_ref() || byref[10])
+    }
 }
 impl<
         A: InteropClass,
@@ -653,6 +753,20 @@ where
             && (K::get_mono_class().is_assignable_from(&classes[10]))
             && (L::get_mono_class().is_assignable_from(&classes[11]))
     }
+    fn compare_byref(byref: &[bool]) -> bool {
+        (!A::is_passed_by_ref() || byref[0])
+            && (!B::is_passed_by_ref() || byref[1])
+            && (!C::is_passed_by_ref() || byref[2])
+            && (!D::is_passed_by_ref() || byref[3])
+            && (!E::is_passed_by_ref() || byref[4])
+            && (!F::is_passed_by_ref() || byref[5])
+            && (!G::is_passed_by_ref() || byref[6])
+            && (!H::is_passed_by_ref() || byref[7])
+            && (!I::is_passed_by_ref() || byref[8])
+            && (!J::is_passed_by_ref() || byref[9])
+            && (!K::is_passed_by_ref() || byref[10])
+            && (!L::is_passed_by_ref() || byref[11])
+    }
 }
 impl<
         A: InteropClass,
@@ -688,6 +802,21 @@ where
             && (L::get_mono_class().is_assignable_from(&classes[11]))
             && (M::get_mono_class().is_assignable_from(&classes[12]))
     }
+    fn compare_byref(byref: &[bool]) -> bool {
+        (!A::is_passed_by_ref() || byref[0])
+            && (!B::is_passed_by_ref() || byref[1])
+            && (!C::is_passed_by_ref() || byref[2])
+            && (!D::is_passed_by_ref() || byref[3])
+            && (!E::is_passed_by_ref() || byref[4])
+            && (!F::is_passed_by_ref() || byref[5])
+            && (!G::is_passed_by_ref() || byref[6])
+            && (!H::is_passed_by_ref() || byref[7])
+            && (!I::is_passed_by_ref() || byref[8])
+            && (!J::is_passed_by_ref() || byref[9])
+            && (!K::is_passed_by_ref() || byref[10])
+            && (!L::is_passed_by_ref() || byref[11])
+            && (!M::is_passed_by_ref() || byref[12])
+    }
 }
 impl<
         A: InteropClass,
@@ -725,6 +854,22 @@ where
             && (M::get_mono_class().is_assignable_from(&classes[12]))
             && (N::get_mono_class().is_assignable_from(&classes[13]))
     }
+    fn compare_byref(byref: &[bool]) -> bool {
+        (!A::is_passed_by_ref() || byref[0])
+            && (!B::is_passed_by_ref() || byref[1])
+            && (!C::is_passed_by_ref() || byref[2])
+            && (!D::is_passed_by_ref() || byref[3])
+            && (!E::is_passed_by_ref() || byref[4])
+            && (!F::is_passed_by_ref() || byref[5])
+            && (!G::is_passed_by_ref() || byref[6])
+            && (!H::is_passed_by_ref() || byref[7])
+            && (!I::is_passed_by_ref() || byref[8])
+            && (!J::is_passed_by_ref() || byref[9])
+            && (!K::is_passed_by_ref() || byref[10])
+            && (!L::is_passed_by_ref() || byref[11])
+            && (!M::is_passed_by_ref() || byref[12])
+            && (!N::is_passed_by_ref() || byref[13])
+    }
 }
 impl<
         A: InteropClass,
@@ -764,6 +909,23 @@ where
             && (N::get_mono_class().is_assignable_from(&classes[13]))
             && (O::get_mono_class().is_assignable_from(&classes[14]))
     }
+    fn compare_byref(byref: &[bool]) -> bool {
+        (!A::is_passed_by_ref() || byref[0])
+            && (!B::is_passed_by_ref() || byref[1])
+            && (!C::is_passed_by_ref() || byref[2])
+            && (!D::is_passed_by_ref() || byref[3])
+            && (!E::is_passed_by_ref() || byref[4])
+            && (!F::is_passed_by_ref() || byref[5])
+            && (!G::is_passed_by_ref() || byref[6])
+            && (!H::is_passed_by_ref() || byref[7])
+            && (!I::is_passed_by_ref() || byref[8])
+            && (!J::is_passed_by_ref() || byref[9])
+            && (!K::is_passed_by_ref() || byref[10])
+            && (!L::is_passed_by_ref() || byref[11])
+            && (!M::is_passed_by_ref() || byref[12])
+            && (!N::is_passed_by_ref() || byref[13])
+            && (!O::is_passed_by_ref() || byref[14])
+    }
 }
 impl<
         A: InteropClass,
@@ -805,5 +967,23 @@ where
             && (O::get_mono_class().is_assignable_from(&classes[14]))
             && (P::get_mono_class().is_assignable_from(&classes[15]))
     }
+    fn compare_byref(byref: &[bool]) -> bool {
+        (!A::is_passed_by_ref() || byref[0])
+            && (!B::is_passed_by_ref() || byref[1])
+            && (!C::is_passed_by_ref() || byref[2])
+            && (!D::is_passed_by_ref() || byref[3])
+            && (!E::is_passed_by_ref() || byref[4])
+            && (!F::is_passed_by_ref() || byref[5])
+            && (!G::is_passed_by_ref() || byref[6])
+            && (!H::is_passed_by_ref() || byref[7])
+            && (!I::is_passed_by_ref() || byref[8])
+            && (!J::is_passed_by_ref() || byref[9])
+            && (!K::is_passed_by_ref() || byref[10])
+            && (!L::is_passed_by_ref() || byref[11])
+            && (!M::is_passed_by_ref() || byref[12])
+            && (!N::is_passed_by_ref() || byref[13])
+            && (!O::is_passed_by_ref() || byref[14])
+            && (!P::is_passed_by_ref() || byref[15])
+    }
 }
 //use lazy_static::*;
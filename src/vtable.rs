@@ -0,0 +1,101 @@
+use crate::binds::MonoVTable;
+use crate::{Class, Domain, Exception, InteropClass, InteropSend, Method, ObjectTrait};
+use std::ffi::c_void;
+/// Safe representation of [`MonoVTable`], per-domain runtime data of a class. Holds static fields of the class, and tracks if its static constructor already ran.
+/// Created by [`Class::get_vtable`].
+#[derive(Copy, Clone)]
+pub struct VTable {
+    vtable_ptr: *mut MonoVTable,
+}
+impl VTable {
+    /// Returns copy of internal pointer representing [`MonoVTable`].
+    #[must_use]
+    pub fn get_ptr(&self) -> *mut MonoVTable {
+        self.vtable_ptr
+    }
+    /// Creates [`VTable`] from *`vtable_ptr`*. If it is not null, returns [`Some`], otherwise [`None`].
+    /// # Safety
+    /// *`vtable_ptr`* must me either a valid pointer to [`MonoVTable`] or null pointer.
+    #[must_use]
+    pub unsafe fn from_ptr(vtable_ptr: *mut MonoVTable) -> Option<Self> {
+        if vtable_ptr.is_null() {
+            return None;
+        }
+        Some(Self { vtable_ptr })
+    }
+    /// Returns class this vtable belongs to.
+    #[must_use]
+    pub fn get_class(&self) -> Class {
+        unsafe { Class::from_ptr(crate::binds::mono_vtable_class(self.vtable_ptr)) }
+            .expect("Could not get class of a vtable!")
+    }
+    /// Returns domain this vtable belongs to.
+    #[must_use]
+    pub fn get_domain(&self) -> Domain {
+        unsafe { Domain::from_ptr(crate::binds::mono_vtable_domain(self.vtable_ptr)) }
+    }
+    /// Runs static constructor of the class, if it did not run yet. Static fields of the class should not be accessed before it runs.
+    /// # Example
+    /// ## C#
+    ///```csharp
+    /// class SomeClass{
+    ///     public static int counter;
+    ///     static SomeClass(){
+    ///         counter = 10;
+    ///     }
+    /// }
+    ///```
+    /// ## Rust
+    ///```no_run
+    /// # use wrapped_mono::*;
+    /// # fn init(some_class:Class,domain:&Domain){
+    /// let vtable = some_class.get_vtable(domain).expect("Could not get vtable!");
+    /// vtable.init_runtime().expect("Static constructor threw an exception!");
+    /// # }
+    ///```
+    /// # Errors
+    /// Returns `System.TypeInitializationException` wrapping the exception thrown by the static constructor.
+    pub fn init_runtime(&self) -> Result<(), Exception> {
+        // `mono_runtime_class_init` aborts if the static constructor throws, so the constructor is run trough `RuntimeHelpers.RunClassConstructor`,
+        // which reports the failure as a normal managed exception.
+        let type_handle = self
+            .get_class()
+            .get_reflection_type(&self.get_domain())
+            .call("get_TypeHandle", ())?
+            .expect("Type handle of a class is null!");
+        #[cfg(feature = "referenced_objects")]
+        let marker = crate::gc::gc_unsafe_enter();
+        let handle = unsafe {
+            *crate::binds::mono_object_unbox(type_handle.get_ptr()).cast::<RuntimeTypeHandle>()
+        };
+        #[cfg(feature = "referenced_objects")]
+        crate::gc::gc_unsafe_exit(marker);
+        let helpers = Class::from_name(
+            &Class::get_object().get_image(),
+            "System.Runtime.CompilerServices",
+            "RuntimeHelpers",
+        )
+        .expect("Could not find class System.Runtime.CompilerServices.RuntimeHelpers!");
+        let run_class_constructor: Method<(RuntimeTypeHandle,)> =
+            Method::find_overload(&helpers, "RunClassConstructor")
+                .expect("Could not find method RuntimeHelpers.RunClassConstructor!");
+        run_class_constructor.invoke(None, (handle,)).map(|_| ())
+    }
+}
+// Managed `System.RuntimeTypeHandle`, a struct containing only the pointer to the type.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct RuntimeTypeHandle {
+    value: *mut c_void,
+}
+unsafe impl InteropSend for RuntimeTypeHandle {}
+impl InteropClass for RuntimeTypeHandle {
+    fn get_mono_class() -> Class {
+        Class::from_name(
+            &Class::get_object().get_image(),
+            "System",
+            "RuntimeTypeHandle",
+        )
+        .expect("Could not find class System.RuntimeTypeHandle!")
+    }
+}
@@ -13,8 +13,9 @@ impl ArgRep {
         let mut arg_type = Vec::with_capacity(tokens.len());
         let mut in_type: bool = false;
         for token in tokens {
+            // Only the first ':' separates name from type, following ones belong to paths(e.g. `wrapped_mono::Object`).
             if let TokenTree::Punct(p) = &token {
-                if p.as_char() == ':' {
+                if p.as_char() == ':' && !in_type {
                     in_type = true;
                     continue;
                 }
@@ -68,7 +69,7 @@ impl ArgRep {
         let type_string: String = self.arg_type.to_string();
         let name = &self.name;
         TokenStream::from_str(&format!(
-            "let {name} = <{type_string}>::get_rust_rep({name});"
+            "let {name} = <{type_string} as wrapped_mono::InteropReceive>::get_rust_rep({name});"
         ))
         .expect("Could not create token stream!")
     }
@@ -64,8 +64,117 @@ fn tok_vec_pop_return(tv: &mut TokVec) -> TokenTree {
     }
     panic!("Could not find return");
 }
+// If *ret* is a `Result<T, E>`, returns `T`.
+fn result_ok_type(ret: &TokenTree) -> Option<TokenTree> {
+    let tokens: Vec<TokenTree> = match ret {
+        TokenTree::Group(g) => g.stream().into_iter().collect(),
+        _ => vec![ret.clone()],
+    };
+    let generic_start = tokens
+        .iter()
+        .position(|tok| matches!(tok, TokenTree::Punct(p) if p.as_char() == '<'))?;
+    match &tokens[..generic_start] {
+        [.., TokenTree::Ident(i)] if i.to_string() == "Result" => (),
+        _ => return None,
+    }
+    let mut depth = 0;
+    let mut ok_type = TokenStream::new();
+    for tok in &tokens[generic_start + 1..] {
+        if let TokenTree::Punct(p) = tok {
+            match p.as_char() {
+                '<' => depth += 1,
+                '>' => depth -= 1,
+                ',' if depth == 0 => break,
+                _ => (),
+            }
+        }
+        ok_type.extend(TokenStream::from(tok.clone()));
+    }
+    Some(TokenTree::Group(proc_macro::Group::new(
+        proc_macro::Delimiter::Parenthesis,
+        ok_type,
+    )))
+}
+// Returns name of the generic type *ret*(e.g. `Option` for `Option<T>`), or `None` if it is not generic.
+fn generic_type_name(ret: &TokenTree) -> Option<String> {
+    let tokens: Vec<TokenTree> = match ret {
+        TokenTree::Group(g) => g.stream().into_iter().collect(),
+        _ => vec![ret.clone()],
+    };
+    let generic_start = tokens
+        .iter()
+        .position(|tok| matches!(tok, TokenTree::Punct(p) if p.as_char() == '<'))?;
+    match &tokens[..generic_start] {
+        [.., TokenTree::Ident(i)] => Some(i.to_string()),
+        _ => None,
+    }
+}
+// Checks if *ret* is an `Array<Dim, T>`. Arrays are sent as object pointers, since they are not FFI-safe.
+fn is_array_type(ret: &TokenTree) -> bool {
+    generic_type_name(ret).is_some_and(|name| name == "Array")
+}
+// Checks if *ret* is an `Option<T>`. Options are sent as object pointers for objects, and as `Nullable<T>` for value types.
+fn is_option_type(ret: &TokenTree) -> bool {
+    generic_type_name(ret).is_some_and(|name| name == "Option")
+}
+// If *ret* is a tuple type(e.g. `(i32, f64)`), returns it.
+fn tuple_type(ret: &TokenTree) -> Option<TokenTree> {
+    let inner = match ret {
+        TokenTree::Group(g) => {
+            let mut tokens = g.stream().into_iter();
+            match (tokens.next(), tokens.next()) {
+                (Some(inner), None) => inner,
+                _ => return None,
+            }
+        }
+        _ => ret.clone(),
+    };
+    match &inner {
+        TokenTree::Group(g)
+            if g.delimiter() == proc_macro::Delimiter::Parenthesis
+                && g.stream()
+                    .into_iter()
+                    .any(|tok| matches!(tok, TokenTree::Punct(p) if p.as_char() == ',')) =>
+        {
+            Some(inner)
+        }
+        _ => None,
+    }
+}
 use std::str::FromStr;
 impl FnRep {
+    // Rust type returned by the function. For functions returning `Result<T, Exception>` it is `T`, since errors are raised as managed exceptions.
+    fn ret_value_type(&self) -> Option<TokenTree> {
+        let ret = self.ret.as_ref()?;
+        Some(result_ok_type(ret).unwrap_or_else(|| ret.clone()))
+    }
+    // Type returned to mono runtime. Tuples are returned as their managed `ValueTuple` representation.
+    fn ffi_ret(&self) -> Option<TokenStream> {
+        let ret = self.ret_value_type()?;
+        if is_array_type(&ret) {
+            return Some(
+                TokenStream::from_str("*mut wrapped_mono::binds::MonoObject")
+                    .expect("Could not create token stream!"),
+            );
+        }
+        if is_option_type(&ret) {
+            return Some(
+                TokenStream::from_str(&format!(
+                    "<{ret} as wrapped_mono::interop::InteropSendOption>::TargetType"
+                ))
+                .expect("Could not create token stream!"),
+            );
+        }
+        match tuple_type(&ret) {
+            Some(tuple) => Some(
+                TokenStream::from_str(&format!(
+                    "<{tuple} as wrapped_mono::interop::InteropSendTuple>::TargetType"
+                ))
+                .expect("Could not create token stream!"),
+            ),
+            None => Some(TokenStream::from(ret)),
+        }
+    }
     fn access(&self) -> &'static str {
         if self.is_pub {
             "pub"
@@ -148,7 +257,7 @@ impl FnRep {
         for arg in &self.args {
             // append the source type for all arguments
             fn_sig_params.extend(TokenStream::from_str(&format!(
-                "<{} as InteropReceive>::SourceType",
+                "<{} as wrapped_mono::InteropReceive>::SourceType,",
                 &arg.get_type_string()
             )));
         }
@@ -157,9 +266,9 @@ impl FnRep {
             proc_macro::Delimiter::Parenthesis,
             fn_sig_params,
         ))));
-        if let Some(return_type) = &self.ret {
+        if let Some(return_type) = self.ffi_ret() {
             res.extend(TokenStream::from_str("->"));
-            res.extend(TokenStream::from(return_type.clone()));
+            res.extend(return_type);
         }
         res.extend(TokenStream::from_str(";"));
         res
@@ -178,9 +287,9 @@ impl FnRep {
         .expect("Could not create token stream!");
         //function args
         stream.extend(self.create_in_arg_list());
-        if let Some(ret) = &self.ret {
+        if let Some(ret) = self.ffi_ret() {
             stream.extend(TokenStream::from_str("->"));
-            stream.extend(TokenStream::from(ret.clone()));
+            stream.extend(ret);
         }
         //argument handlers
         let mut inner: TokenStream = TokenStream::new();
@@ -195,19 +304,33 @@ impl FnRep {
             &self.name
         )));
         let mut call_args = TokenStream::new();
-        //let curr = 0;
-        //let arg_count = self.args.len();
         for arg in &self.args {
-            //let c = if curr < arg_count - 1{','}else{' '};
-            call_args.extend(TokenStream::from_str(&arg.name));
+            call_args.extend(TokenStream::from_str(&format!("{},", arg.name)));
         }
         inner.extend(TokenStream::from(TokenTree::Group(proc_macro::Group::new(
             proc_macro::Delimiter::Parenthesis,
             call_args,
         ))));
         inner.extend(TokenStream::from_str(";"));
-        if self.ret.is_some() {
-            inner.extend(TokenStream::from_str("return unsafe{fnc_call_res_val.return_value_to_mono()};"));
+        // Errors are raised as managed exceptions.
+        if self.ret.as_ref().and_then(result_ok_type).is_some() {
+            inner.extend(TokenStream::from_str(
+                "let fnc_call_res_val = match fnc_call_res_val{
+                    Ok(val) => val,
+                    Err(exception) => unsafe{ wrapped_mono::Exception::raise(&exception) },
+                };",
+            ));
+        }
+        if let Some(ret) = self.ret_value_type() {
+            if is_array_type(&ret) {
+                inner.extend(TokenStream::from_str("let mut fnc_call_res_val = fnc_call_res_val; return fnc_call_res_val.get_ffi_ptr().cast();"));
+            } else if is_option_type(&ret) {
+                inner.extend(TokenStream::from_str("return unsafe{wrapped_mono::interop::InteropSendOption::option_to_mono(fnc_call_res_val)};"));
+            } else if tuple_type(&ret).is_some() {
+                inner.extend(TokenStream::from_str("return unsafe{wrapped_mono::interop::InteropSendTuple::tuple_to_mono(fnc_call_res_val)};"));
+            } else {
+                inner.extend(TokenStream::from_str("return unsafe{fnc_call_res_val.return_value_to_mono()};"));
+            }
         }
 
         stream.extend(TokenStream::from(TokenTree::Group(proc_macro::Group::new(
@@ -97,7 +97,28 @@ pub fn add_internal_call(args: TokenStream) -> TokenStream {
 /// Macro creating a wrapper around a function making it able to be exposed as internal call.
 /// # Restrictions
 /// Arguments of function with [`macro@invokable`] atribute must be of types that implement `InteropReceive` trait.
-/// Return type of the function must implement `InvokeSend` trait.
+/// Return type of the function must implement `InteropSend` trait. Both traits can be implemented for custom types, and the macro will use them
+/// to convert arguments and return values, so they don't need to be in scope where the macro is used.
+/// # Nullable objects
+/// Managed objects which can be null should be received and returned as `Option<T>`(e.g. `Option<Object>`), with `None` representing `null`.
+/// Generic and fully qualified types(e.g. `Option<wrapped_mono::object::Object>`) can be used in both argument and return positions.
+/// Nullable value types(e.g. `int?`) can be received and returned as `Option<T>`(e.g. `Option<i32>`), and are passed as `System.Nullable<T>`.
+/// # Arrays
+/// Arrays(e.g. `Array<Dim1D, i32>`) can be received and returned, so internal calls can build new arrays and return them to managed code.
+/// # Tuples
+/// Tuples of up to 7 elements can be used as arguments and return values, and are passed to and from managed code as `System.ValueTuple`(e.g. `(int, double)` in C#).
+/// Each element is converted using its own `InteropReceive` or `InteropSend` implementation.
+/// # Raising exceptions
+/// If function returns `Result<T, Exception>`, `T` is returned to managed code on `Ok`, and the `Exception` is raised on `Err`, so it can be caught by managed code.
+/// ```rust
+/// #[invokable]
+/// fn checked_divide(a:i32,b:i32)->Result<i32,Exception>{
+///     if b == 0{
+///         return Err(Exception::divide_by_zero());
+///     }
+///     Ok(a/b)
+/// }
+/// ```
 /// # Example
 // Function:
 /// ```rust
@@ -108,11 +129,11 @@ pub fn add_internal_call(args: TokenStream) -> TokenStream {
 /// ```
 /// Will create a wrapper and a function type needed to expose it it mono runtime
 /// ```rust
-/// extern "C" fn print_message_invokable(message:*mut <String as InteropReceive>::SourceType){
-///     let message = <String>::get_rust_rep(message);
+/// extern "C" fn print_message_invokable(message:<String as wrapped_mono::InteropReceive>::SourceType){
+///     let message = <String as wrapped_mono::InteropReceive>::get_rust_rep(message);
 ///     let res = print_message(message);
 /// }
-/// pub type extern fn print_message_fn_type = extern "C" fn (<String as InteropReceive>::SourceType);
+/// type print_message_fn_type = extern "C" fn (<String as wrapped_mono::InteropReceive>::SourceType,);
 /// ```
 #[proc_macro_attribute]
 pub fn invokable(_attr_ts: TokenStream, fn_ts: TokenStream) -> TokenStream {
@@ -229,8 +250,10 @@ pub fn derive_recive(input: TokenStream) -> TokenStream {
     } else {
         panic!("{input_type} is not a valid type!");
     }
-    let mut res =
-        TokenStream::from_str(&format!("impl InteropReceive for {input_name}")).expect(TS_CR_FAIL);
+    let mut res = TokenStream::from_str(&format!(
+        "impl wrapped_mono::InteropReceive for {input_name}"
+    ))
+    .expect(TS_CR_FAIL);
     let mut inner_res = TokenStream::from_str("type SourceType = ").expect(TS_CR_FAIL);
     inner_res.extend(TokenStream::from(TokenTree::Group(proc_macro::Group::new(
         proc_macro::Delimiter::Parenthesis,
@@ -254,6 +277,73 @@ pub fn derive_recive(input: TokenStream) -> TokenStream {
     dumping::dump_stream(&res);
     res
 }
+/// Implements `InteropReceive` and `InteropSend` for a `#[repr(C)]` struct, passing it to and from managed code by a byte-wise copy.
+/// Managed side should be a struct with `[StructLayout(LayoutKind.Sequential)]` and fields of matching types in the same order.
+/// # Restrictions
+/// The struct must be `#[repr(C)]` and `Copy`, and all of its fields must be blittable: `Copy` types implementing `InteropSend`, such as numbers or other [`macro@MonoBlittable`] structs.
+/// # Example
+/// ```rust
+/// #[derive(MonoBlittable, Clone, Copy)]
+/// #[repr(C)]
+/// struct Vec3 {
+///     x: f32,
+///     y: f32,
+///     z: f32,
+/// }
+/// ```
+#[proc_macro_derive(MonoBlittable)]
+pub fn derive_blittable(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    let is_repr_c = input.attrs.iter().any(|attr| {
+        attr.path.is_ident("repr")
+            && attr
+                .parse_args_with(
+                    syn::punctuated::Punctuated::<syn::Ident, syn::Token![,]>::parse_terminated,
+                )
+                .is_ok_and(|reprs| reprs.iter().any(|repr| repr == "C"))
+    });
+    if !is_repr_c {
+        return syn::Error::new_spanned(
+            &input.ident,
+            "MonoBlittable can be only derived for #[repr(C)] structs, since other layouts don't match the managed one!",
+        )
+        .to_compile_error()
+        .into();
+    }
+    let fields = match &input.data {
+        syn::Data::Struct(data) => &data.fields,
+        _ => {
+            return syn::Error::new_spanned(
+                &input.ident,
+                "MonoBlittable can be only derived for structs!",
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
+    let field_types = fields.iter().map(|field| &field.ty);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let res = quote::quote! {
+        const _: () = {
+            fn assert_blittable<T: Copy + wrapped_mono::InteropSend>() {}
+            fn assert_fields #impl_generics () #where_clause {
+                #(assert_blittable::<#field_types>();)*
+            }
+        };
+        impl #impl_generics wrapped_mono::InteropReceive for #name #ty_generics #where_clause {
+            type SourceType = Self;
+            fn get_rust_rep(mono_arg: Self::SourceType) -> Self {
+                mono_arg
+            }
+        }
+        unsafe impl #impl_generics wrapped_mono::InteropSend for #name #ty_generics #where_clause {}
+    };
+    let res: TokenStream = res.into();
+    #[cfg(feature = "dump_macro_results")]
+    dumping::dump_stream(&res);
+    res
+}
 #[cfg(feature = "dump_macro_results")]
 mod dumping {
     static mut HAS_BEGUN_DUMP: bool = false;
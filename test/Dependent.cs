@@ -0,0 +1,3 @@
+class DependentClass : AbstractClass{
+    public override void AbstractFunction(){}
+}
@@ -1,4 +1,5 @@
 using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
 class Test{
     [MethodImplAttribute(MethodImplOptions.InternalCall)]
     public static extern void ConfoirmConstuctorCall(object self);
@@ -13,6 +14,115 @@ class Test{
     ///Gets a null object
     [MethodImplAttribute(MethodImplOptions.InternalCall)]
     public static extern object GetObject();
+    [MethodImplAttribute(MethodImplOptions.InternalCall)]
+    public static extern void ThrowArgumentNull();
+    public static bool CatchArgumentNull(){
+        try{
+            ThrowArgumentNull();
+        }
+        catch(System.ArgumentNullException){
+            return true;
+        }
+        return false;
+    }
+    [MethodImplAttribute(MethodImplOptions.InternalCall)]
+    public static extern int CheckedDivide(int x);
+    public static bool CatchCheckedDivide(){
+        if(CheckedDivide(2) != 5){
+            return false;
+        }
+        try{
+            CheckedDivide(0);
+        }
+        catch(System.ArgumentNullException){
+            return true;
+        }
+        return false;
+    }
+    [MethodImplAttribute(MethodImplOptions.InternalCall)]
+    public static extern int DoubleMeters(int meters);
+    public static int CallDoubleMeters(int meters){
+        return DoubleMeters(meters);
+    }
+    [MethodImplAttribute(MethodImplOptions.InternalCall)]
+    public static extern (int, double) GetPair();
+    [MethodImplAttribute(MethodImplOptions.InternalCall)]
+    public static extern double SumPair((int, double) pair);
+    public static bool CheckPair(){
+        (int, double) pair = GetPair();
+        return pair.Item1 == 5 && pair.Item2 == 2.5 && SumPair((3, 0.5)) == 3.5;
+    }
+    [MethodImplAttribute(MethodImplOptions.InternalCall)]
+    public static extern bool IsString(object obj);
+    public static bool CallIsString(){
+        return IsString("some string") && !IsString(5);
+    }
+    [MethodImplAttribute(MethodImplOptions.InternalCall)]
+    public static extern Vec3 DoubleVec3(Vec3 vec);
+    public static bool CheckVec3(){
+        Vec3 vec = DoubleVec3(new Vec3{x = 1, y = 2, z = 3});
+        return vec.x == 2 && vec.y == 4 && vec.z == 6;
+    }
+    public static object GetBoxedVec3(){
+        return new Vec3{x = 1, y = 2, z = 3};
+    }
+    [MethodImplAttribute(MethodImplOptions.InternalCall)]
+    public static extern void PassScalars(bool b, float x, double y);
+    public static void CallPassScalars(){
+        PassScalars(true, 1.5f, 0.1);
+        PassScalars(false, -3.25f, 1e300);
+    }
+    [MethodImplAttribute(MethodImplOptions.InternalCall)]
+    public static extern object GetSomeObject();
+    [MethodImplAttribute(MethodImplOptions.InternalCall)]
+    public static extern bool IsNullObject(object obj);
+    public static bool CheckOptionalObjects(){
+        return GetObject() == null && (int)GetSomeObject() == 5 && IsNullObject(null) && !IsNullObject("some string");
+    }
+    [MethodImplAttribute(MethodImplOptions.InternalCall)]
+    public static extern void PassStringArray(string[] strings);
+    public static void CallPassStringArray(){
+        PassStringArray(new string[]{"a","b","c"});
+    }
+    [MethodImplAttribute(MethodImplOptions.InternalCall)]
+    public static extern int CallFunc(System.Func<int,int> func);
+    public static int CallDoubling(){
+        return CallFunc((int x) => x * 2);
+    }
+    [MethodImplAttribute(MethodImplOptions.InternalCall)]
+    public static extern int[] MakeSquares(int len);
+    public static int SumMadeSquares(){
+        int[] arr = MakeSquares(4);
+        int sum = 0;
+        foreach(int val in arr){
+            sum += val;
+        }
+        return arr.Length * 100 + sum;
+    }
+    [MethodImplAttribute(MethodImplOptions.InternalCall)]
+    public static extern bool CheckGuidBytes(System.Guid guid, byte[] expected);
+    public static bool PassGuid(){
+        System.Guid guid = new System.Guid("00112233-4455-6677-8899-aabbccddeeff");
+        return CheckGuidBytes(guid, guid.ToByteArray());
+    }
+    public static object GetBoxedGuid(){
+        return new System.Guid("00112233-4455-6677-8899-aabbccddeeff");
+    }
+    [MethodImplAttribute(MethodImplOptions.InternalCall)]
+    public static extern int DescribeNullable(int? x);
+    public static int CallDescribeNullable(){
+        return DescribeNullable(5) * 10 + DescribeNullable(null);
+    }
+    public static int NullableOrDefault(int? x){
+        return x ?? -1;
+    }
+    [MethodImplAttribute(MethodImplOptions.InternalCall)]
+    public static extern int? MaybeDouble(int x);
+    public static int CallMaybeDouble(){
+        int? doubled = MaybeDouble(4);
+        int? missing = MaybeDouble(-1);
+        return (doubled ?? 0) * 10 + (missing.HasValue ? 1 : 0);
+    }
     public static void Main(string[] args){
         string tmp = "|";
         foreach(string arg in args){
@@ -40,3 +150,9 @@ class Secondary{
         Test.ConfoirmConstuctorCall(this);
     }
 }
+[StructLayout(LayoutKind.Sequential)]
+struct Vec3{
+    public float x;
+    public float y;
+    public float z;
+}
@@ -1,6 +1,13 @@
 interface IInterfaceOne{
     void SomeInterfaceFunction();
 }
+interface IInterfaceTwo{
+    void OtherInterfaceFunction();
+}
+class TwoInterfaces : IInterfaceOne, IInterfaceTwo{
+    public void SomeInterfaceFunction(){}
+    public void OtherInterfaceFunction(){}
+}
 enum CLikeEnum{
     Val = 1,
     Val2 = 2,
@@ -16,6 +23,10 @@ class CtorTestClass: CtorTestClassParrent{
 }
 class TestFunctions : IInterfaceOne{
     public int someField = 5;
+    static int counter = 0;
+    public static int GetCounter(){
+        return counter;
+    }
     public static string PrintTypes(System.Type[] types){
     	string s = "Types:\"";
     	foreach(System.Type type in types){
@@ -41,6 +52,66 @@ class TestFunctions : IInterfaceOne{
     public static int[,] Get2DIntArray(){
         return new int[8,16];
     }
+    public static int[,] GetFilled2DIntArray(){
+        int[,] arr = new int[3,4];
+        for(int i = 0; i < 3; i++){
+            for(int j = 0; j < 4; j++){
+                arr[i,j] = i * 10 + j;
+            }
+        }
+        return arr;
+    }
+    public static int SumIntArray(int[] arr){
+        int sum = 0;
+        foreach(int val in arr){
+            sum += val;
+        }
+        return sum;
+    }
+    public static int SumByteArray(byte[] arr){
+        int sum = 0;
+        foreach(byte val in arr){
+            sum += val;
+        }
+        return sum;
+    }
+    public static float GetFloatAt(float[] arr,int index){
+        return arr[index];
+    }
+    public static bool TryParseInt(string text, out int value){
+        return int.TryParse(text, out value);
+    }
+    public static void AppendSuffix(ref string text){
+        text += "!";
+    }
+    public static string LoadAssemblyByName(string name){
+        return System.Reflection.Assembly.Load(name).GetName().Name;
+    }
+    public static string JoinArgs(int number, string text, double fraction){
+        return number + text + fraction.ToString(System.Globalization.CultureInfo.InvariantCulture);
+    }
+    public static int CollectAndGetLength(string text){
+        System.GC.Collect();
+        System.GC.WaitForPendingFinalizers();
+        return text.Length;
+    }
+    public static T Identity<T>(T value){
+        return value;
+    }
+    public static int?[] IncrementNullableIntArray(int?[] arr){
+        int?[] res = new int?[arr.Length];
+        for(int i = 0; i < arr.Length; i++){
+            res[i] = arr[i] + 1;
+        }
+        return res;
+    }
+    public static int[] GetSquaresIntArray(int len){
+        int[] arr = new int[len];
+        for(int i = 0; i < len; i++){
+            arr[i] = i * i;
+        }
+        return arr;
+    }
     public int GetSomeFiled(){
         return this.someField;
     }
@@ -122,12 +193,171 @@ class TestFunctions : IInterfaceOne{
     public static TestDelegate GetDelegate(){
         return new TestDelegate(DelFNC);
     }
+    public static System.Func<string,int> GetCollectingLengthDelegate(){
+        return (string text) => {
+            System.GC.Collect();
+            System.GC.WaitForPendingFinalizers();
+            return text.Length;
+        };
+    }
+    public static int Add(int a,int b){
+        return a + b;
+    }
+    public static int Divide(int a,int b){
+        return a / b;
+    }
     public static void ExceptionThrower(){
         throw new System.InvalidOperationException("Logfile cannot be read-only");
     }
+    public static void NestedExceptionThrower(){
+        try{
+            ExceptionThrower();
+        }
+        catch(System.Exception inner){
+            throw new System.ApplicationException("Outer failure", inner);
+        }
+    }
+    public static void ThrowOnThread(){
+        System.Threading.Thread thread = new System.Threading.Thread(() => {
+            throw new System.ArgumentException("Thrown on a background thread");
+        });
+        thread.Start();
+        thread.Join();
+    }
 } 
+class ThreadStaticHolder{
+    [System.ThreadStatic]
+    public static int perThread;
+}
+class PropertyHolder{
+    public int Number{ get; set; } = 7;
+    public string Label{ get; set; } = "label";
+}
+class FieldFlagsHolder{
+    public int instanceField = 0;
+    private static long staticField = 0;
+}
+class BaseClass{}
+class MiddleClass : BaseClass{}
+class DerivedClass : MiddleClass, IInterfaceOne{
+    public void SomeInterfaceFunction(){}
+}
+class EventHolder{
+    public event System.Action<int> SomethingHappened;
+    public void Raise(int value){
+        SomethingHappened?.Invoke(value);
+    }
+}
+public abstract class AbstractClass{
+    public abstract void AbstractFunction();
+}
+public sealed class SealedClass{}
+class ValueHolder{
+    int value = 42;
+    public int GetValue(){
+        return value;
+    }
+    public int Add(int x){
+        return value + x;
+    }
+}
+class DerivedValueHolder : ValueHolder{}
+class MethodIterHolder{
+    public int Get(){
+        return 0;
+    }
+    public int Add(int x){
+        return x + 1;
+    }
+    public int AddTwice(int x){
+        return x + 2;
+    }
+}
+class ShadowBase{
+    public int value = 1;
+}
+class ShadowDerived : ShadowBase{
+    public static new long value = 2;
+}
+class StaticCtorHolder{
+    public static int value;
+    static StaticCtorHolder(){
+        value = 10;
+    }
+}
+class ThrowingStaticCtor{
+    public static int value;
+    static ThrowingStaticCtor(){
+        throw new System.InvalidOperationException("Static constructor failed");
+    }
+}
+[System.Runtime.InteropServices.StructLayout(System.Runtime.InteropServices.LayoutKind.Sequential)]
+class LayoutHolder{
+    public long first = 1;
+    public int second = 2;
+    public long third = 3;
+}
+class DateTimeProvider{
+    public static System.DateTime GetUnixEpoch(){
+        return System.DateTime.UnixEpoch;
+    }
+    public static long GetTicks(System.DateTime time){
+        return time.Ticks;
+    }
+}
+class EmptyClass{}
+class LongHolder{
+    public long value = 0;
+}
+class ReferenceHolder{
+    public object reference;
+    public string text;
+    public string greeting = "hi";
+}
+class DelegateHolder{
+    public int lastValue = 0;
+    public System.Action<int> callback;
+    public DelegateHolder(){
+        callback = (int value) => { lastValue = value; };
+    }
+}
 static class TestMainEntry{
     public static void Main(string[] ars){
         return;
     }
 }
+[System.AttributeUsage(System.AttributeTargets.Class | System.AttributeTargets.Method)]
+public class PluginAttribute : System.Attribute{
+    public string Name{ get; }
+    public PluginAttribute(string name){
+        Name = name;
+    }
+}
+[System.AttributeUsage(System.AttributeTargets.Method)]
+public class BenchmarkAttribute : System.Attribute{
+    public int Iterations{ get; }
+    public BenchmarkAttribute(int iterations){
+        Iterations = iterations;
+    }
+}
+[Plugin("test plugin")]
+class PluginClass{
+    [Benchmark(100)]
+    public static void BenchmarkedFunction(){}
+    public static void NotBenchmarkedFunction(){}
+}
+enum Color : int{
+    Red = 1,
+    Green = 2,
+}
+enum SmallColor : byte{
+    Blue = 3,
+}
+class EnumProvider{
+    public static object GetGreen(){
+        return Color.Green;
+    }
+    public static object GetBlue(){
+        return SmallColor.Blue;
+    }
+}